@@ -0,0 +1,214 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use common::{Answer, ParseError, Solution};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2, 5]));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert!((stats.stddev.as_secs_f64() - 2f64.sqrt() * 1e-3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        let stats = Stats::from_samples(&ms(&[7]));
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
+
+/// Summary statistics of the time taken by repeated runs of the same task.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Compute the statistics of a non-empty list of samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+        let median = match runs % 2 {
+            0 => (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2,
+            _ => sorted[runs / 2],
+        };
+        let seconds: Vec<f64> = sorted.iter().map(|s| s.as_secs_f64()).collect();
+        let mean = seconds.iter().sum::<f64>() / runs as f64;
+        let variance = seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+        Self {
+            runs,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Stage of the solution of a puzzle that gets benchmarked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(u32),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+/// Timing statistics of a single stage of a puzzle.
+pub struct BenchRow {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Benchmark for a whole puzzle.
+///
+/// Takes the day number, the input text, the parts that should be benchmarked and the number of
+/// times each one of them should run.
+pub type Bencher = fn(u32, &str, &[u32], usize) -> Result<Vec<BenchRow>, ParseError>;
+
+/// Time a function over a number of runs.
+fn time_runs<T>(runs: usize, mut f: impl FnMut() -> T) -> Stats {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+    }
+    Stats::from_samples(&samples)
+}
+
+/// Benchmark the parser and the requested parts of the puzzle.
+///
+/// Parts without a solution are skipped.
+pub fn bench<S: Solution>(
+    day: u32,
+    input: &str,
+    parts: &[u32],
+    runs: usize,
+) -> Result<Vec<BenchRow>, ParseError> {
+    let parsed = S::parse(input)?;
+    let stats = time_runs(runs, || S::parse(input));
+    let mut rows = vec![BenchRow {
+        day,
+        stage: Stage::Parse,
+        stats,
+    }];
+    for part in parts {
+        let solve = || match part {
+            1 => S::part_one(&parsed),
+            _ => S::part_two(&parsed),
+        };
+        if solve() == Answer::Unsolved {
+            eprintln!("Day {day} has no solution for part {part}");
+            continue;
+        }
+        rows.push(BenchRow {
+            day,
+            stage: Stage::Part(*part),
+            stats: time_runs(runs, solve),
+        });
+    }
+    Ok(rows)
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e3
+}
+
+pub fn print_table(rows: &[BenchRow]) {
+    println!(
+        "{:>3}  {:>5}  {:>5}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "Day", "Part", "Runs", "Min (ms)", "Median (ms)", "Mean (ms)", "Stddev (ms)"
+    );
+    for row in rows {
+        println!(
+            "{:>3}  {:>5}  {:>5}  {:>12.3}  {:>12.3}  {:>12.3}  {:>12.3}",
+            row.day,
+            row.stage.to_string(),
+            row.stats.runs,
+            as_ms(row.stats.min),
+            as_ms(row.stats.median),
+            as_ms(row.stats.mean),
+            as_ms(row.stats.stddev),
+        );
+    }
+}
+
+/// Return the short hash of the current git commit, or an empty string if it's not available.
+fn current_commit() -> String {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+/// Append the results to a CSV file, writing its header if the file is new.
+///
+/// Every row is labelled with the time of the benchmark and the current git commit, so results
+/// from different commits can be compared.
+pub fn write_csv(fname: &str, rows: &[BenchRow]) -> io::Result<()> {
+    let is_new = !Path::new(fname).exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(fname)?;
+    if is_new {
+        writeln!(
+            file,
+            "timestamp,commit,day,part,runs,min_ms,median_ms,mean_ms,stddev_ms"
+        )?;
+    }
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|t| t.as_secs())
+        .unwrap_or(0);
+    let commit = current_commit();
+    for row in rows {
+        writeln!(
+            file,
+            "{timestamp},{commit},{},{},{},{:.6},{:.6},{:.6},{:.6}",
+            row.day,
+            row.stage,
+            row.stats.runs,
+            as_ms(row.stats.min),
+            as_ms(row.stats.median),
+            as_ms(row.stats.mean),
+            as_ms(row.stats.stddev),
+        )?;
+    }
+    Ok(())
+}
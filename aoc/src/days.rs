@@ -0,0 +1,273 @@
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+use common::{Answer, ParseError, Rng, Solution};
+use grid::frames::{Frames, Rgb};
+
+use crate::bench::{bench, Bencher};
+use crate::resources::{Meter, Usage};
+
+/// Answer of a single part of a puzzle, along with the time and resources it took to solve it.
+pub struct Row {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub usage: Usage,
+}
+
+/// Solver for a whole puzzle.
+///
+/// Takes the day number, the input text and the parts that should be solved.
+pub type Solver = fn(u32, &str, &[u32]) -> Result<Vec<Row>, ParseError>;
+
+/// Generator of synthetic inputs for a puzzle.
+pub enum Generator {
+    /// Inputs of a given size
+    Sized(fn(&mut Rng, usize) -> String),
+    /// Maps of a given width and height
+    Map(fn(&mut Rng, usize, usize) -> String),
+}
+
+/// Recorder of the frames of a simulation, given the input text.
+pub type Animator = fn(&str, &mut Frames) -> Result<(), Box<dyn Error>>;
+
+/// Animation of a puzzle whose solution simulates something, one frame per step.
+pub struct Animation {
+    /// Colors of the cells of the map
+    pub palette: &'static [(char, Rgb)],
+    pub animate: Animator,
+}
+
+pub struct Day {
+    pub number: u32,
+    pub solve: Solver,
+    pub bench: Bencher,
+    pub generate: Generator,
+    pub animation: Option<Animation>,
+}
+
+/// Parse the input and solve the requested parts of the puzzle.
+///
+/// The input is parsed only once, and the time and resources it takes are added to the first part
+/// that gets solved. Parts without a solution are skipped.
+fn solve<S: Solution>(day: u32, input: &str, parts: &[u32]) -> Result<Vec<Row>, ParseError> {
+    let mut meter = Meter::start();
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let mut parse_time = start.elapsed();
+    let mut rows = vec![];
+    for part in parts {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part_one(&parsed),
+            _ => S::part_two(&parsed),
+        };
+        let elapsed = start.elapsed() + parse_time;
+        let usage = meter.stop();
+        if answer == Answer::Unsolved {
+            eprintln!("Day {day} has no solution for part {part}");
+            continue;
+        }
+        parse_time = Duration::ZERO;
+        meter = Meter::start();
+        rows.push(Row {
+            day,
+            part: *part,
+            answer,
+            elapsed,
+            usage,
+        });
+    }
+    Ok(rows)
+}
+
+pub const DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        solve: solve::<day_01::Day01>,
+        bench: bench::<day_01::Day01>,
+        generate: Generator::Sized(day_01::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 2,
+        solve: solve::<day_02::Day02>,
+        bench: bench::<day_02::Day02>,
+        generate: Generator::Sized(day_02::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 3,
+        solve: solve::<day_03::Day03>,
+        bench: bench::<day_03::Day03>,
+        generate: Generator::Sized(day_03::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 4,
+        solve: solve::<day_04::Day04>,
+        bench: bench::<day_04::Day04>,
+        generate: Generator::Sized(day_04::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 5,
+        solve: solve::<day_05::Day05>,
+        bench: bench::<day_05::Day05>,
+        generate: Generator::Sized(day_05::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 6,
+        solve: solve::<day_06::Day06>,
+        bench: bench::<day_06::Day06>,
+        generate: Generator::Sized(day_06::generate::generate),
+        animation: Some(Animation {
+            palette: day_06::animate::PALETTE,
+            animate: day_06::animate::animate,
+        }),
+    },
+    Day {
+        number: 7,
+        solve: solve::<day_07::Day07>,
+        bench: bench::<day_07::Day07>,
+        generate: Generator::Sized(day_07::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 8,
+        solve: solve::<day_08::Day08>,
+        bench: bench::<day_08::Day08>,
+        generate: Generator::Sized(day_08::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 9,
+        solve: solve::<day_09::Day09>,
+        bench: bench::<day_09::Day09>,
+        generate: Generator::Sized(day_09::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 10,
+        solve: solve::<day_10::Day10>,
+        bench: bench::<day_10::Day10>,
+        generate: Generator::Sized(day_10::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 11,
+        solve: solve::<day_11::Day11>,
+        bench: bench::<day_11::Day11>,
+        generate: Generator::Sized(day_11::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 12,
+        solve: solve::<day_12::Day12>,
+        bench: bench::<day_12::Day12>,
+        generate: Generator::Map(day_12::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 13,
+        solve: solve::<day_13::Day13>,
+        bench: bench::<day_13::Day13>,
+        generate: Generator::Sized(day_13::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 14,
+        solve: solve::<day_14::Day14>,
+        bench: bench::<day_14::Day14>,
+        generate: Generator::Sized(day_14::generate::generate),
+        animation: Some(Animation {
+            palette: day_14::animate::PALETTE,
+            animate: day_14::animate::animate,
+        }),
+    },
+    Day {
+        number: 15,
+        solve: solve::<day_15::Day15>,
+        bench: bench::<day_15::Day15>,
+        generate: Generator::Sized(day_15::generate::generate),
+        animation: Some(Animation {
+            palette: day_15::animate::PALETTE,
+            animate: day_15::animate::animate,
+        }),
+    },
+    Day {
+        number: 16,
+        solve: solve::<day_16::Day16>,
+        bench: bench::<day_16::Day16>,
+        generate: Generator::Sized(day_16::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 17,
+        solve: solve::<day_17::Day17>,
+        bench: bench::<day_17::Day17>,
+        generate: Generator::Sized(day_17::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 18,
+        solve: solve::<day_18::Day18>,
+        bench: bench::<day_18::Day18>,
+        generate: Generator::Sized(day_18::generate::generate),
+        animation: Some(Animation {
+            palette: day_18::animate::PALETTE,
+            animate: day_18::animate::animate,
+        }),
+    },
+    Day {
+        number: 19,
+        solve: solve::<day_19::Day19>,
+        bench: bench::<day_19::Day19>,
+        generate: Generator::Sized(day_19::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 20,
+        solve: solve::<day_20::Day20>,
+        bench: bench::<day_20::Day20>,
+        generate: Generator::Sized(day_20::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 21,
+        solve: solve::<day_21::Day21>,
+        bench: bench::<day_21::Day21>,
+        generate: Generator::Sized(day_21::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 22,
+        solve: solve::<day_22::Day22>,
+        bench: bench::<day_22::Day22>,
+        generate: Generator::Sized(day_22::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 23,
+        solve: solve::<day_23::Day23>,
+        bench: bench::<day_23::Day23>,
+        generate: Generator::Sized(day_23::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 24,
+        solve: solve::<day_24::Day24>,
+        bench: bench::<day_24::Day24>,
+        generate: Generator::Sized(day_24::generate::generate),
+        animation: None,
+    },
+    Day {
+        number: 25,
+        solve: solve::<day_25::Day25>,
+        bench: bench::<day_25::Day25>,
+        generate: Generator::Sized(day_25::generate::generate),
+        animation: None,
+    },
+];
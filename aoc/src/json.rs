@@ -0,0 +1,175 @@
+use std::fmt;
+
+use common::Answer;
+
+use crate::days::Row;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_serialize() {
+        let value = Json::Object(vec![
+            ("day".to_string(), Json::from(17_u32)),
+            ("negative".to_string(), Json::Integer(-3)),
+            ("ratio".to_string(), Json::Float(0.5)),
+            ("empty".to_string(), Json::Array(vec![])),
+            ("missing".to_string(), Json::Null),
+            (
+                "text".to_string(),
+                Json::from("a \"quoted\"\\path\n\ttab\u{1}"),
+            ),
+        ]);
+        let expected = r#"{"day":17,"negative":-3,"ratio":0.5,"empty":[],"missing":null,"text":"a \"quoted\"\\path\n\ttab\u0001"}"#;
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[test]
+    fn test_answers() {
+        let sequence = Json::from(&Answer::from(vec![4_u8, 3]));
+        assert_eq!(sequence.to_string(), "[4,3]");
+        let point = Json::from(&Answer::from((46_usize, 28_usize)));
+        assert_eq!(point.to_string(), "[46,28]");
+        let big = Json::from(&Answer::from(u64::MAX));
+        assert_eq!(big.to_string(), "18446744073709551615");
+        assert_eq!(Json::from(&Answer::Unsolved).to_string(), "null");
+    }
+
+    #[test]
+    fn test_day_object() {
+        let rows = vec![Row {
+            day: 23,
+            part: 2,
+            answer: Answer::from("co,de"),
+            elapsed: Duration::from_micros(1500),
+            usage: Default::default(),
+        }];
+        let expected =
+            r#"{"day":23,"parts":[{"part":2,"answer":"co,de","type":"text","elapsed_ms":1.5}]}"#;
+        assert_eq!(day_object(23, &rows).to_string(), expected);
+    }
+}
+
+/// JSON value, with just what we need to serialize the results.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Integer(i128),
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in text.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Integer(value) => write!(f, "{value}"),
+            // JSON has no representation for NaN or infinities
+            Json::Float(value) if !value.is_finite() => write!(f, "null"),
+            Json::Float(value) => write!(f, "{value}"),
+            Json::String(text) => write_string(f, text),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<u32> for Json {
+    fn from(value: u32) -> Self {
+        Json::Integer(value as i128)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(text: &str) -> Self {
+        Json::String(text.to_string())
+    }
+}
+
+impl From<&Answer> for Json {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Signed(value) => Json::Integer(*value as i128),
+            Answer::Unsigned(value) => Json::Integer(*value as i128),
+            Answer::Sequence(values) => {
+                Json::Array(values.iter().map(|v| Json::Integer(*v as i128)).collect())
+            }
+            Answer::Point(x, y) => {
+                Json::Array(vec![Json::Integer(*x as i128), Json::Integer(*y as i128)])
+            }
+            Answer::Text(text) => Json::String(text.to_string()),
+            Answer::Unsolved => Json::Null,
+        }
+    }
+}
+
+/// Build the JSON object with the answers of a single day.
+pub fn day_object(day: u32, rows: &[Row]) -> Json {
+    let parts = rows
+        .iter()
+        .filter(|row| row.day == day)
+        .map(|row| {
+            Json::Object(vec![
+                ("part".to_string(), Json::from(row.part)),
+                ("answer".to_string(), Json::from(&row.answer)),
+                ("type".to_string(), Json::from(row.answer.kind())),
+                (
+                    "elapsed_ms".to_string(),
+                    Json::Float(row.elapsed.as_secs_f64() * 1e3),
+                ),
+            ])
+        })
+        .collect();
+    Json::Object(vec![
+        ("day".to_string(), Json::from(day)),
+        ("parts".to_string(), Json::Array(parts)),
+    ])
+}
+
+/// Print one JSON object per line for each day that got solved.
+pub fn print_days(rows: &[Row]) {
+    let mut days: Vec<u32> = rows.iter().map(|row| row.day).collect();
+    days.dedup();
+    for day in days {
+        println!("{}", day_object(day, rows));
+    }
+}
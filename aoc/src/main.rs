@@ -0,0 +1,434 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+use common::{parallel, Rng};
+use days::{Generator, Row, DAYS};
+use grid::frames::{Format, Frames, Palette};
+
+mod bench;
+mod days;
+mod json;
+mod resources;
+
+#[global_allocator]
+static ALLOCATOR: resources::CountingAllocator = resources::CountingAllocator;
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part 1|2] [--input PATH] [--workers N] [--resources]
+               [--format table|json]
+       aoc bench <day|all> [--part 1|2] [--input PATH] [--workers N] [--runs N] [--csv PATH]
+       aoc generate <day|all> --size N [--height N] [--seed S] [--output PATH]
+       aoc animate <day> --output DIR [--input PATH] [--every N] [--scale N] [--format ppm|pgm]
+                   [--palette SPEC]";
+
+/// Default number of times each stage runs in the `bench` command.
+const DEFAULT_RUNS: usize = 10;
+
+/// Default seed of the random number generator in the `generate` command.
+const DEFAULT_SEED: u64 = 2024;
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    /// Solve the puzzles once and print their answers
+    Run,
+    /// Solve the puzzles many times and print timing statistics
+    Bench,
+    /// Write synthetic inputs for the puzzles
+    Generate,
+    /// Write the frames of the simulation of a puzzle as images
+    Animate,
+}
+
+/// Options passed to the `run`, `bench`, `generate` and `animate` commands.
+struct Options {
+    command: Command,
+    days: Vec<u32>,
+    part: Option<u32>,
+    input: Option<String>,
+    runs: usize,
+    csv: Option<String>,
+    resources: bool,
+    json: bool,
+    size: Option<usize>,
+    /// Height of the maps of the days that generate them, the same as the size by default
+    height: Option<usize>,
+    seed: u64,
+    output: Option<String>,
+    every: usize,
+    scale: usize,
+    image_format: Format,
+    palette: Option<String>,
+    workers: usize,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(|s| s.as_str()) {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some("generate") => Command::Generate,
+        Some("animate") => Command::Animate,
+        Some(command) => return Err(format!("Unknown command '{command}'")),
+        None => return Err("Missing command".to_string()),
+    };
+    let days = match args.next().map(|s| s.as_str()) {
+        Some("all") => DAYS.iter().map(|d| d.number).collect(),
+        Some(day) => match day.parse::<u32>() {
+            Ok(day) if (1..=25).contains(&day) => vec![day],
+            _ => return Err(format!("Invalid day '{day}'")),
+        },
+        None => return Err("Missing day".to_string()),
+    };
+    let mut options = Options {
+        command,
+        days,
+        part: None,
+        input: None,
+        runs: DEFAULT_RUNS,
+        csv: None,
+        resources: false,
+        json: false,
+        size: None,
+        height: None,
+        seed: DEFAULT_SEED,
+        output: None,
+        every: 1,
+        scale: 1,
+        image_format: Format::Ppm,
+        palette: None,
+        workers: 1,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" if matches!(options.command, Command::Run | Command::Bench) => {
+                match args.next().map(|s| s.as_str()) {
+                    Some("1") => options.part = Some(1),
+                    Some("2") => options.part = Some(2),
+                    _ => return Err("The --part option should be 1 or 2".to_string()),
+                }
+            }
+            "--input" if options.command != Command::Generate => match args.next() {
+                Some(path) => options.input = Some(path.to_string()),
+                None => return Err("Missing path for --input".to_string()),
+            },
+            "--workers" if matches!(options.command, Command::Run | Command::Bench) => {
+                match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(workers)) if workers > 0 => options.workers = workers,
+                    _ => {
+                        return Err("The --workers option should be a positive integer".to_string())
+                    }
+                }
+            }
+            "--resources" if options.command == Command::Run => options.resources = true,
+            "--format" if options.command == Command::Run => {
+                match args.next().map(|s| s.as_str()) {
+                    Some("table") => options.json = false,
+                    Some("json") => options.json = true,
+                    _ => return Err("The --format option should be table or json".to_string()),
+                }
+            }
+            "--runs" if options.command == Command::Bench => {
+                match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(runs)) if runs > 0 => options.runs = runs,
+                    _ => return Err("The --runs option should be a positive integer".to_string()),
+                }
+            }
+            "--csv" if options.command == Command::Bench => match args.next() {
+                Some(path) => options.csv = Some(path.to_string()),
+                None => return Err("Missing path for --csv".to_string()),
+            },
+            "--size" if options.command == Command::Generate => {
+                match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(size)) if size > 0 => options.size = Some(size),
+                    _ => return Err("The --size option should be a positive integer".to_string()),
+                }
+            }
+            "--height" if options.command == Command::Generate => {
+                match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(height)) if height > 0 => options.height = Some(height),
+                    _ => return Err("The --height option should be a positive integer".to_string()),
+                }
+            }
+            "--seed" if options.command == Command::Generate => {
+                match args.next().map(|s| s.parse::<u64>()) {
+                    Some(Ok(seed)) => options.seed = seed,
+                    _ => return Err("The --seed option should be an integer".to_string()),
+                }
+            }
+            "--output" if matches!(options.command, Command::Generate | Command::Animate) => {
+                match args.next() {
+                    Some(path) => options.output = Some(path.to_string()),
+                    None => return Err("Missing path for --output".to_string()),
+                }
+            }
+            "--every" if options.command == Command::Animate => {
+                match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(every)) if every > 0 => options.every = every,
+                    _ => return Err("The --every option should be a positive integer".to_string()),
+                }
+            }
+            "--scale" if options.command == Command::Animate => {
+                match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(scale)) if scale > 0 => options.scale = scale,
+                    _ => return Err("The --scale option should be a positive integer".to_string()),
+                }
+            }
+            "--format" if options.command == Command::Animate => {
+                match args.next().map(|s| s.as_str()) {
+                    Some("ppm") => options.image_format = Format::Ppm,
+                    Some("pgm") => options.image_format = Format::Pgm,
+                    _ => return Err("The --format option should be ppm or pgm".to_string()),
+                }
+            }
+            "--palette" if options.command == Command::Animate => match args.next() {
+                Some(spec) => options.palette = Some(spec.to_string()),
+                None => return Err("Missing colors for --palette".to_string()),
+            },
+            e => return Err(format!("Unknown argument '{e}'")),
+        }
+    }
+    if options.json && options.resources {
+        return Err("The --resources option can't be used with --format json".to_string());
+    }
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err("The --input option can only be used when running a single day".to_string());
+    }
+    if options.command == Command::Generate {
+        if options.size.is_none() {
+            return Err("The generate command needs the --size option".to_string());
+        }
+        if options.output.is_none() && options.days.len() > 1 {
+            return Err("Generating all the days needs an --output directory".to_string());
+        }
+    }
+    if options.command == Command::Animate {
+        if options.days.len() > 1 {
+            return Err("The animate command can only be used with a single day".to_string());
+        }
+        if options.output.is_none() {
+            return Err("The animate command needs an --output directory".to_string());
+        }
+    }
+    Ok(options)
+}
+
+/// Read the puzzle input from a file, or from the standard input if the path is `-`.
+fn read_input(fname: &str) -> io::Result<String> {
+    if fname == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+    fs::read_to_string(fname)
+}
+
+/// Return the default location of the input file for a given day.
+fn default_input(day: u32) -> String {
+    format!("day-{day:02}/data/input")
+}
+
+/// Return the parts of the puzzle that should be solved.
+fn get_parts(part: Option<u32>) -> Vec<u32> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    let width = rows
+        .iter()
+        .map(|row| row.answer.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>12}",
+        "Day", "Part", "Answer", "Time (ms)"
+    );
+    let mut total = Duration::ZERO;
+    for row in rows {
+        println!(
+            "{:>3}  {:>4}  {:<width$}  {:>12.3}",
+            row.day,
+            row.part,
+            row.answer.to_string(),
+            row.elapsed.as_secs_f64() * 1e3
+        );
+        total += row.elapsed;
+    }
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>12.3}",
+        "",
+        "",
+        "Total",
+        total.as_secs_f64() * 1e3
+    );
+}
+
+fn as_mib(bytes: u64) -> f64 {
+    bytes as f64 / (1024.0 * 1024.0)
+}
+
+/// Print the CPU time, memory and allocations used to solve each part.
+fn print_resources(rows: &[Row]) {
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  {:>14}  {:>12}  {:>15}  {:>15}",
+        "Day",
+        "Part",
+        "User (ms)",
+        "Sys (ms)",
+        "Peak RSS (MiB)",
+        "Allocations",
+        "Allocated (MiB)",
+        "Peak heap (MiB)"
+    );
+    for row in rows {
+        let peak_rss = match row.usage.peak_rss {
+            Some(bytes) => format!("{:.2}", as_mib(bytes)),
+            None => "-".to_string(),
+        };
+        println!(
+            "{:>3}  {:>4}  {:>10.3}  {:>10.3}  {:>14}  {:>12}  {:>15.2}  {:>15.2}",
+            row.day,
+            row.part,
+            row.usage.user.as_secs_f64() * 1e3,
+            row.usage.system.as_secs_f64() * 1e3,
+            peak_rss,
+            row.usage.allocations,
+            as_mib(row.usage.allocated_bytes as u64),
+            as_mib(row.usage.peak_heap as u64),
+        );
+    }
+}
+
+/// Write the synthetic inputs of the requested days.
+///
+/// Each day gets its own generator, seeded with the same value, so the inputs of a day don't
+/// depend on which other days get generated. A single input goes to the standard output unless
+/// there's an output path, while several of them go to `day-XX.txt` files in the output directory.
+fn generate(options: &Options) -> io::Result<()> {
+    let size = options.size.unwrap();
+    for number in options.days.iter() {
+        let day = &DAYS[*number as usize - 1];
+        let mut rng = Rng::new(options.seed);
+        let input = match day.generate {
+            Generator::Sized(generate) => generate(&mut rng, size),
+            Generator::Map(generate) => generate(&mut rng, size, options.height.unwrap_or(size)),
+        };
+        match &options.output {
+            None => print!("{input}"),
+            Some(path) if options.days.len() == 1 => fs::write(path, input)?,
+            Some(dir) => {
+                fs::create_dir_all(dir)?;
+                fs::write(Path::new(dir).join(format!("day-{number:02}.txt")), input)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write the frames of the simulation of a single day into the output directory.
+fn animate(options: &Options) -> Result<(), String> {
+    let number = options.days[0];
+    let Some(animation) = &DAYS[number as usize - 1].animation else {
+        return Err(format!("Day {number} has no animation"));
+    };
+    let mut palette = Palette::new(animation.palette);
+    if let Some(spec) = &options.palette {
+        palette.set_overrides(spec)?;
+    }
+    let fname = match &options.input {
+        Some(fname) => fname.to_string(),
+        None => default_input(number),
+    };
+    let input =
+        read_input(&fname).map_err(|e| format!("Couldn't read input file '{fname}': {e}"))?;
+    let dir = options.output.as_ref().unwrap();
+    let mut frames = Frames::new(dir, palette)
+        .map_err(|e| format!("Couldn't create output directory '{dir}': {e}"))?;
+    frames.set_every(options.every);
+    frames.set_scale(options.scale);
+    frames.set_format(options.image_format);
+    (animation.animate)(&input, &mut frames).map_err(|e| e.to_string())?;
+    eprintln!("Wrote {} frames to '{dir}'", frames.written());
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+    if options.command == Command::Generate {
+        if let Err(e) = generate(&options) {
+            eprintln!("Couldn't write the inputs: {e}");
+            process::exit(1);
+        }
+        return;
+    }
+    if options.command == Command::Animate {
+        if let Err(e) = animate(&options) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    parallel::set_workers(options.workers);
+    let mut rows = vec![];
+    let mut bench_rows = vec![];
+    for number in options.days.iter() {
+        let day = &DAYS[*number as usize - 1];
+        let fname = match &options.input {
+            Some(fname) => fname.to_string(),
+            None => default_input(day.number),
+        };
+        let input = match read_input(&fname) {
+            Ok(input) => input,
+            Err(_) => {
+                eprintln!("Skipping day {number}: couldn't read input file '{fname}'");
+                continue;
+            }
+        };
+        let parts = get_parts(options.part);
+        let result = match options.command {
+            Command::Run => (day.solve)(day.number, &input, &parts).map(|r| rows.extend(r)),
+            Command::Bench => {
+                (day.bench)(day.number, &input, &parts, options.runs).map(|r| bench_rows.extend(r))
+            }
+            Command::Generate | Command::Animate => unreachable!(),
+        };
+        if let Err(e) = result {
+            eprintln!("Skipping day {number}: couldn't parse input: {e}");
+        }
+    }
+    match options.command {
+        Command::Run if options.json => json::print_days(&rows),
+        Command::Run => {
+            print_table(&rows);
+            if options.resources {
+                println!();
+                print_resources(&rows);
+            }
+        }
+        Command::Generate | Command::Animate => unreachable!(),
+        Command::Bench => {
+            bench::print_table(&bench_rows);
+            if let Some(fname) = &options.csv {
+                if let Err(e) = bench::write_csv(fname, &bench_rows) {
+                    eprintln!("Couldn't write results to '{fname}': {e}");
+                    process::exit(1);
+                }
+            }
+        }
+    }
+}
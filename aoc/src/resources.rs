@@ -0,0 +1,210 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[test]
+    fn test_counts_allocations() {
+        let meter = Meter::start();
+        let values: Vec<u64> = black_box(Vec::with_capacity(1000));
+        let usage = meter.stop();
+        drop(values);
+        // Other tests might be allocating at the same time, so we can only check lower bounds
+        assert!(usage.allocations >= 1);
+        assert!(usage.allocated_bytes >= 8000);
+        assert!(usage.peak_heap >= 8000);
+    }
+
+    #[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+    #[test]
+    fn test_cpu_time_and_rss() {
+        let meter = Meter::start();
+        let mut total: u64 = 0;
+        for i in 0..5_000_000 {
+            total = black_box(total.wrapping_add(i));
+        }
+        let usage = meter.stop();
+        assert!(usage.user + usage.system > Duration::ZERO);
+        assert!(usage.peak_rss.unwrap() > 0);
+    }
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_HEAP: AtomicUsize = AtomicUsize::new(0);
+static PEAK_HEAP: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator that counts the allocations done through the system allocator.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT_HEAP.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_HEAP.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        CURRENT_HEAP.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            // Count a reallocation as freeing the old block and allocating a new one
+            CURRENT_HEAP.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::record_allocation(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// Resources used while running a piece of code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Usage {
+    /// CPU time spent in user mode
+    pub user: Duration,
+    /// CPU time spent in kernel mode
+    pub system: Duration,
+    /// Peak resident memory of the process in bytes, if it could be read
+    pub peak_rss: Option<u64>,
+    /// Number of heap allocations (reallocations included)
+    pub allocations: usize,
+    /// Total number of bytes requested to the allocator
+    pub allocated_bytes: usize,
+    /// Maximum number of bytes allocated in the heap at the same time
+    pub peak_heap: usize,
+}
+
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+mod rusage {
+    use std::time::Duration;
+
+    #[repr(C)]
+    struct Timeval {
+        tv_sec: i64,
+        tv_usec: i64,
+    }
+
+    /// Layout of `struct rusage` on 64-bit Linux
+    #[repr(C)]
+    struct RUsage {
+        ru_utime: Timeval,
+        ru_stime: Timeval,
+        ru_other: [i64; 14],
+    }
+
+    const RUSAGE_SELF: i32 = 0;
+
+    extern "C" {
+        fn getrusage(who: i32, usage: *mut RUsage) -> i32;
+    }
+
+    fn as_duration(time: &Timeval) -> Duration {
+        Duration::new(time.tv_sec as u64, time.tv_usec as u32 * 1000)
+    }
+
+    /// Return the user and system CPU time used by the process so far.
+    pub fn cpu_times() -> (Duration, Duration) {
+        let mut usage = RUsage {
+            ru_utime: Timeval {
+                tv_sec: 0,
+                tv_usec: 0,
+            },
+            ru_stime: Timeval {
+                tv_sec: 0,
+                tv_usec: 0,
+            },
+            ru_other: [0; 14],
+        };
+        // SAFETY: `usage` is a valid, writable `struct rusage` for this platform
+        if unsafe { getrusage(RUSAGE_SELF, &mut usage) } != 0 {
+            return (Duration::ZERO, Duration::ZERO);
+        }
+        (as_duration(&usage.ru_utime), as_duration(&usage.ru_stime))
+    }
+}
+
+#[cfg(not(all(target_os = "linux", target_pointer_width = "64")))]
+mod rusage {
+    use std::time::Duration;
+
+    /// CPU times aren't available on this platform.
+    pub fn cpu_times() -> (Duration, Duration) {
+        (Duration::ZERO, Duration::ZERO)
+    }
+}
+
+/// Read the peak resident memory of the process (`VmHWM`) from `/proc/self/status`.
+fn peak_rss() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|l| l.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// Try to reset the peak resident memory of the process to its current value.
+///
+/// This only works on Linux. Elsewhere the peak is the one since the process started.
+fn reset_peak_rss() {
+    let _ = fs::write("/proc/self/clear_refs", "5");
+}
+
+/// Measure the resources used between its creation and the call to `stop`.
+pub struct Meter {
+    user: Duration,
+    system: Duration,
+    allocations: usize,
+    allocated_bytes: usize,
+}
+
+impl Meter {
+    pub fn start() -> Self {
+        reset_peak_rss();
+        PEAK_HEAP.store(CURRENT_HEAP.load(Ordering::Relaxed), Ordering::Relaxed);
+        let (user, system) = rusage::cpu_times();
+        Self {
+            user,
+            system,
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn stop(&self) -> Usage {
+        let (user, system) = rusage::cpu_times();
+        Usage {
+            user: user.saturating_sub(self.user),
+            system: system.saturating_sub(self.system),
+            peak_rss: peak_rss(),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.allocated_bytes,
+            peak_heap: PEAK_HEAP.load(Ordering::Relaxed),
+        }
+    }
+}
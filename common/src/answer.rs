@@ -0,0 +1,141 @@
+use std::fmt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-42_i32).to_string(), "-42");
+        assert_eq!(Answer::from(42_u64).to_string(), "42");
+        assert_eq!(Answer::from(vec![4_u8, 6, 3]).to_string(), "4,6,3");
+        assert_eq!(Answer::from((46_usize, 28_usize)).to_string(), "46,28");
+        assert_eq!(Answer::from("co,de,ka,ta").to_string(), "co,de,ka,ta");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::from(42_i32), Answer::from(42_u64));
+        assert_ne!(Answer::from(-42_i64), Answer::from(42_u32));
+        assert_eq!(Answer::from(vec![1_u8, 2]), Answer::from(vec![1_u8, 2]));
+        assert_ne!(Answer::from(vec![1_u8, 2]), Answer::from("1,2"));
+        assert_ne!(Answer::Unsolved, Answer::from(0_u32));
+    }
+}
+
+/// Answer to a single part of a puzzle.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Sequence(Vec<u8>),
+    Point(usize, usize),
+    Text(String),
+    Unsolved,
+}
+
+impl Answer {
+    /// Return the name of the kind of answer.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Signed(_) => "signed",
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Sequence(_) => "sequence",
+            Answer::Point(_, _) => "point",
+            Answer::Text(_) => "text",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Sequence(values) => {
+                let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                write!(f, "{}", values.join(","))
+            }
+            Answer::Point(x, y) => write!(f, "{x},{y}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+// Integers are compared by their value, regardless of the variant that holds them, so an answer
+// computed as an i32 matches the same one computed as an u64.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Unsigned(b)) | (Answer::Unsigned(b), Answer::Signed(a)) => {
+                *a as i128 == *b as i128
+            }
+            (Answer::Sequence(a), Answer::Sequence(b)) => a == b,
+            (Answer::Point(ax, ay), Answer::Point(bx, by)) => (ax, ay) == (bx, by),
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Unsolved, Answer::Unsolved) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<Vec<u8>> for Answer {
+    fn from(values: Vec<u8>) -> Self {
+        Answer::Sequence(values)
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from(point: (usize, usize)) -> Self {
+        Answer::Point(point.0, point.1)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::Path;
+
+use crate::{Answer, Solution};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("42\n\n4,6,3\n");
+        assert_eq!(answers, vec!["42".to_string(), "4,6,3".to_string()]);
+        let answers = parse_answers("  1215  \n");
+        assert_eq!(answers, vec!["1215".to_string()]);
+    }
+
+    #[test]
+    fn test_missing_files_are_skipped() {
+        struct Never;
+        impl Solution for Never {
+            type Input = ();
+            fn parse(_: &str) -> Result<Self::Input, crate::ParseError> {
+                panic!("shouldn't parse anything");
+            }
+            fn part_one(_: &Self::Input) -> crate::Answer {
+                panic!("shouldn't solve anything");
+            }
+        }
+        check_recorded_answers::<Never>("/non/existent/day");
+    }
+}
+
+/// Read the recorded answers, one for each part in a different line.
+fn parse_answers(content: &str) -> Vec<String> {
+    let answers = content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect();
+    answers
+}
+
+/// Check that a solution still produces the answers recorded for the personal input of a day.
+///
+/// The input is read from `data/input` and the answers from `data/answers` inside the directory
+/// of the day, where the first line holds the answer to part one and the second one (if any) the
+/// answer to part two. If either file is missing the check is skipped, since personal inputs are
+/// not always available.
+pub fn check_recorded_answers<S: Solution>(day_dir: &str) {
+    let data = Path::new(day_dir).join("data");
+    let (Ok(input), Ok(answers)) = (
+        fs::read_to_string(data.join("input")),
+        fs::read_to_string(data.join("answers")),
+    ) else {
+        eprintln!("Skipping recorded answers check: missing files in {data:?}");
+        return;
+    };
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => panic!("Couldn't parse input: {e}"),
+    };
+    for (i, expected) in parse_answers(&answers).iter().enumerate() {
+        let answer = match i {
+            0 => S::part_one(&parsed),
+            1 => S::part_two(&parsed),
+            _ => panic!("Found more than two answers in {data:?}"),
+        };
+        assert_eq!(
+            answer.to_string(),
+            *expected,
+            "wrong answer to part {}",
+            i + 1
+        );
+    }
+}
+
+/// Read the example of a part, where `examples` holds the example file of each part relative to
+/// the directory of the day.
+pub fn read_example(day_dir: &str, examples: &[&str], part: u32) -> String {
+    let path = Path::new(day_dir).join(examples[part as usize - 1]);
+    match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) => panic!("Couldn't read example {path:?}: {e}"),
+    }
+}
+
+/// Check that a solution produces the expected answer to the example of each part.
+///
+/// These are the examples that the binary of the day solves with `--example`.
+pub fn check_examples<S: Solution>(day_dir: &str, examples: &[&str], expected: &[&str]) {
+    for (i, expected) in expected.iter().enumerate() {
+        let input = read_example(day_dir, examples, i as u32 + 1);
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => panic!("Couldn't parse example: {e}"),
+        };
+        let answer: Answer = match i {
+            0 => S::part_one(&parsed),
+            _ => S::part_two(&parsed),
+        };
+        assert_eq!(
+            answer.to_string(),
+            *expected,
+            "wrong answer to the example of part {}",
+            i + 1
+        );
+    }
+}
@@ -0,0 +1,255 @@
+use std::cell::OnceCell;
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::process;
+
+use crate::parallel;
+
+/// Location of the personal puzzle input, relative to the directory of the day.
+const INPUT: &str = "data/input";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let parsed = Args::parse(&args(&[]), 2).unwrap();
+        assert_eq!(parsed.input, None);
+        assert!(parsed.solves(1) && parsed.solves(2) && !parsed.example);
+        let parsed = Args::parse(&args(&["-", "--part", "2"]), 2).unwrap();
+        assert_eq!(parsed.input, Some("-".to_string()));
+        assert!(!parsed.solves(1) && parsed.solves(2));
+        let parsed = Args::parse(&args(&["--example", "--part", "1"]), 2).unwrap();
+        assert!(parsed.example && parsed.solves(1) && !parsed.solves(2));
+        let parsed = Args::parse(&args(&["--workers", "4"]), 2).unwrap();
+        assert_eq!(parsed.workers, Some(4));
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = ["--external DIR", "--explain"];
+        let parsed = Args::parse_with(&args(&["--external", "tmp", "-"]), 2, &options).unwrap();
+        assert_eq!(parsed.option("--external"), Some("tmp"));
+        assert_eq!(parsed.input, Some("-".to_string()));
+        assert_eq!(parsed.option("--other"), None);
+        assert!(!parsed.flag("--explain"));
+        let parsed = Args::parse_with(&args(&["--explain", "-"]), 2, &options).unwrap();
+        assert!(parsed.flag("--explain"));
+        assert_eq!(parsed.input, Some("-".to_string()));
+        assert!(Args::parse_with(&args(&["--external"]), 2, &options).is_err());
+        assert!(Args::parse(&args(&["--external", "tmp"]), 2).is_err());
+    }
+
+    #[test]
+    fn test_example_of_each_part() {
+        let examples = ["data/test_input", "data/test_input_2"];
+        let parsed = Args::parse(&args(&["--example"]), 2).unwrap();
+        assert_eq!(parsed.path(&examples, 1), Some("data/test_input"));
+        assert_eq!(parsed.path(&examples, 2), Some("data/test_input_2"));
+        assert!(!parsed.shares_input(&examples));
+        assert!(parsed.shares_input(&["data/test_input", "data/test_input"]));
+        let parsed = Args::parse(&args(&["--example", "--part", "2"]), 2).unwrap();
+        assert_eq!(parsed.first_part(), 2);
+        assert!(parsed.shares_input(&examples));
+        // Without examples, both parts read the same input
+        let parsed = Args::parse(&args(&[]), 2).unwrap();
+        assert_eq!(parsed.path(&examples, 2), Some(INPUT));
+        assert!(parsed.shares_input(&examples));
+        let parsed = Args::parse(&args(&["-"]), 2).unwrap();
+        assert_eq!(parsed.path(&examples, 1), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Args::parse(&args(&["--part", "3"]), 2).is_err());
+        assert!(Args::parse(&args(&["--part", "2"]), 1).is_err());
+        assert!(Args::parse(&args(&["--part"]), 2).is_err());
+        assert!(Args::parse(&args(&["a", "b"]), 2).is_err());
+        assert!(Args::parse(&args(&["input", "--example"]), 2).is_err());
+        assert!(Args::parse(&args(&["--verbose"]), 2).is_err());
+        assert!(Args::parse(&args(&["--workers", "0"]), 2).is_err());
+    }
+}
+
+/// Return true if the option spec, like `--policy PATH`, is for an option that takes a value.
+fn takes_value(spec: &str, option: &str) -> bool {
+    spec.split_once(" ").is_some_and(|(name, _)| name == option)
+}
+
+/// Command-line arguments of the binary of a single day.
+#[derive(Debug)]
+pub struct Args {
+    /// Path to the input file, or `-` to read it from the standard input
+    pub input: Option<String>,
+    /// Part of the puzzle to solve, or both of them if missing
+    pub part: Option<u32>,
+    /// Solve the example from the puzzle description instead of the personal input
+    pub example: bool,
+    /// Number of threads that the loops of the solutions get split into
+    pub workers: Option<usize>,
+    /// Values of the options that are specific to the day (empty for flags)
+    pub options: HashMap<String, String>,
+    /// Standard input, once it's read
+    stdin: OnceCell<String>,
+}
+
+impl Args {
+    /// Parse the arguments (without the program name) for a day that solves `parts` parts.
+    pub fn parse(args: &[String], parts: u32) -> Result<Self, String> {
+        Self::parse_with(args, parts, &[])
+    }
+
+    /// Parse the arguments, along with the given options that are specific to the day.
+    ///
+    /// Options that take a value are given along with its name, like `--policy PATH`, while flags
+    /// are given alone, like `--explain`.
+    pub fn parse_with(args: &[String], parts: u32, options: &[&str]) -> Result<Self, String> {
+        let mut result = Args {
+            input: None,
+            part: None,
+            example: false,
+            workers: None,
+            options: HashMap::new(),
+            stdin: OnceCell::new(),
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => match args.next().map(|s| s.parse::<u32>()) {
+                    Some(Ok(part)) if (1..=parts).contains(&part) => result.part = Some(part),
+                    Some(Ok(2)) => return Err("Part two isn't solved yet".to_string()),
+                    _ => return Err("The --part option should be 1 or 2".to_string()),
+                },
+                "--example" => result.example = true,
+                "--workers" => match args.next().map(|s| s.parse::<usize>()) {
+                    Some(Ok(workers)) if workers > 0 => result.workers = Some(workers),
+                    _ => {
+                        return Err("The --workers option should be a positive integer".to_string())
+                    }
+                },
+                option if options.contains(&option) => {
+                    result.options.insert(option.to_string(), String::new());
+                }
+                option if options.iter().any(|o| takes_value(o, option)) => match args.next() {
+                    Some(value) => {
+                        result.options.insert(option.to_string(), value.to_string());
+                    }
+                    None => return Err(format!("Missing value for {option}")),
+                },
+                path if result.input.is_none() && (path == "-" || !path.starts_with("-")) => {
+                    result.input = Some(path.to_string())
+                }
+                e => return Err(format!("Unknown argument '{e}'")),
+            }
+        }
+        if result.example && result.input.is_some() {
+            return Err("The --example option can't be used with an input path".to_string());
+        }
+        Ok(result)
+    }
+
+    /// Parse the arguments of the running program, or exit after printing its usage.
+    ///
+    /// The number of workers, if given, is set for the current thread.
+    pub fn from_env(parts: u32) -> Self {
+        Self::from_env_with(parts, &[])
+    }
+
+    /// Parse the arguments of the running program, along with the options specific to the day.
+    pub fn from_env_with(parts: u32, options: &[&str]) -> Self {
+        let mut args = env::args();
+        let program = args.next().unwrap_or_default();
+        let args: Vec<String> = args.collect();
+        let args = match Self::parse_with(&args, parts, options) {
+            Ok(args) => args,
+            Err(e) => {
+                let options: String = options.iter().map(|o| format!(" [{o}]")).collect();
+                eprintln!(
+                    "{e}\nUsage: {program} [PATH|-] [--part 1|2] [--example] [--workers N]{options}"
+                );
+                process::exit(2);
+            }
+        };
+        if let Some(workers) = args.workers {
+            parallel::set_workers(workers);
+        }
+        args
+    }
+
+    /// Return the value of an option specific to the day, if it was given.
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|value| value.as_str())
+    }
+
+    /// Return true if a flag specific to the day was given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    /// Return true if the given part of the puzzle should be solved.
+    pub fn solves(&self, part: u32) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Return the first part of the puzzle that should be solved.
+    pub fn first_part(&self) -> u32 {
+        self.part.unwrap_or(1)
+    }
+
+    /// Return true if all the parts to solve read the same input.
+    ///
+    /// `examples` holds the example file of each part, so they only differ when solving the
+    /// examples of both parts.
+    pub fn shares_input(&self, examples: &[&str]) -> bool {
+        let shared =
+            !self.example || self.part.is_some() || examples.iter().all(|e| *e == examples[0]);
+        shared
+    }
+
+    /// Return the path of the input file of a part, or `None` to read the standard input.
+    ///
+    /// It's the given path, the example of the part when solving the examples, or the personal
+    /// input otherwise. `examples` holds the example file of each part.
+    fn path<'a>(&'a self, examples: &[&'a str], part: u32) -> Option<&'a str> {
+        let path = match &self.input {
+            Some(path) if path == "-" => None,
+            Some(path) => Some(path.as_str()),
+            None if self.example => Some(examples[part as usize - 1]),
+            None => Some(INPUT),
+        };
+        path
+    }
+
+    /// Read the puzzle input of a part.
+    ///
+    /// The standard input is only read once, and shared by both parts.
+    pub fn read_input(&self, examples: &[&str], part: u32) -> io::Result<String> {
+        match self.path(examples, part) {
+            Some(fname) => fs::read_to_string(fname),
+            None => {
+                if let Some(input) = self.stdin.get() {
+                    return Ok(input.clone());
+                }
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                let _ = self.stdin.set(input.clone());
+                Ok(input)
+            }
+        }
+    }
+
+    /// Open the puzzle input of a part to read it line by line.
+    pub fn open_input(&self, examples: &[&str], part: u32) -> io::Result<Box<dyn BufRead>> {
+        match self.path(examples, part) {
+            Some(fname) => Ok(Box::new(BufReader::new(File::open(fname)?))),
+            None => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generate_numbers(rng: &mut Rng, size: usize) -> String {
+        let numbers: Vec<String> = (0..size).map(|_| rng.range(0, 100).to_string()).collect();
+        numbers.join("\n")
+    }
+
+    fn shrink_numbers(input: &str) -> Vec<String> {
+        let numbers: Vec<&str> = input.lines().collect();
+        without_each(&numbers)
+            .iter()
+            .map(|numbers| numbers.join("\n"))
+            .collect()
+    }
+
+    fn sum(input: &str) -> u32 {
+        input.lines().map(|n| n.parse::<u32>().unwrap()).sum()
+    }
+
+    #[test]
+    fn test_without_each() {
+        let removed = without_each(&[1, 2, 3]);
+        assert_eq!(removed, vec![vec![2, 3], vec![1, 3], vec![1, 2]]);
+        assert!(without_each::<u8>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_agreement() {
+        check_differential(50, 20, generate_numbers, shrink_numbers, sum, |input| {
+            input
+                .lines()
+                .fold(0, |total, n| total + n.parse::<u32>().unwrap())
+        });
+    }
+
+    #[test]
+    fn test_shrinks_failing_input() {
+        // A buggy "sum" that ignores numbers above 90
+        let buggy = |input: &str| {
+            let total: u32 = input
+                .lines()
+                .map(|n| n.parse::<u32>().unwrap())
+                .filter(|n| *n <= 90)
+                .sum();
+            total
+        };
+        let failure = find_failure(50, 20, generate_numbers, shrink_numbers, buggy, sum);
+        let failure = failure.expect("the bug should be found");
+        let number: u32 = failure.input.parse().unwrap();
+        assert!(number > 90);
+        assert_eq!(failure.solution, Ok(0));
+        assert_eq!(failure.oracle, Ok(number));
+    }
+
+    #[test]
+    fn test_panics_count_as_failures() {
+        let panicking = |input: &str| {
+            if input.lines().count() > 3 {
+                panic!("too many numbers");
+            }
+            sum(input)
+        };
+        let failure = find_failure(50, 20, generate_numbers, shrink_numbers, panicking, sum);
+        let failure = failure.expect("the panic should be found");
+        assert_eq!(failure.input.lines().count(), 4);
+        assert!(failure.solution.is_err());
+    }
+}
+
+/// Smallest input found where a solution and its oracle disagree.
+#[derive(Debug)]
+pub struct Failure<T> {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    /// Result of the solution, or an error if it panicked
+    pub solution: Result<T, String>,
+    /// Result of the oracle, or an error if it panicked
+    pub oracle: Result<T, String>,
+}
+
+/// Return every copy of `items` that has a single one of them removed.
+///
+/// Handy to write shrinkers that remove lines, blocks or columns of an input.
+pub fn without_each<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let copies = (0..items.len())
+        .map(|i| {
+            let mut copy = items.to_vec();
+            copy.remove(i);
+            copy
+        })
+        .collect();
+    copies
+}
+
+/// Run a function on an input, turning panics into errors.
+fn outcome<T>(f: &impl Fn(&str) -> T, input: &str) -> Result<T, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| f(input))) {
+        Ok(result) => Ok(result),
+        Err(payload) => {
+            let message = match payload.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => payload
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .unwrap_or_default(),
+            };
+            Err(format!("panicked: {message}"))
+        }
+    }
+}
+
+/// Look for an input where the solution and the oracle disagree, and shrink it.
+///
+/// Inputs are built by `generate` with seeds from zero to `cases` and sizes that cycle from one
+/// to `max_size`. The first failing input gets shrunk by repeatedly replacing it with the first
+/// of its `shrink` candidates that still fails, until none of them does. Candidates where both
+/// functions panic (like inputs that can't be parsed) count as agreements.
+pub fn find_failure<T, G, S, F, O>(
+    cases: u64,
+    max_size: usize,
+    generate: G,
+    shrink: S,
+    solution: F,
+    oracle: O,
+) -> Option<Failure<T>>
+where
+    T: PartialEq,
+    G: Fn(&mut Rng, usize) -> String,
+    S: Fn(&str) -> Vec<String>,
+    F: Fn(&str) -> T,
+    O: Fn(&str) -> T,
+{
+    let disagree = |input: &str| {
+        let (expected, result) = (outcome(&oracle, input), outcome(&solution, input));
+        let disagreement = match (&expected, &result) {
+            (Err(_), Err(_)) => None,
+            _ if expected == result => None,
+            _ => Some((result, expected)),
+        };
+        disagreement
+    };
+    for seed in 0..cases {
+        let size = seed as usize % max_size + 1;
+        let mut input = generate(&mut Rng::new(seed), size);
+        let Some(mut results) = disagree(&input) else {
+            continue;
+        };
+        while let Some((candidate, candidate_results)) = shrink(&input)
+            .into_iter()
+            .find_map(|candidate| disagree(&candidate).map(|r| (candidate, r)))
+        {
+            input = candidate;
+            results = candidate_results;
+        }
+        let (solution, oracle) = results;
+        return Some(Failure {
+            seed,
+            size,
+            input,
+            solution,
+            oracle,
+        });
+    }
+    None
+}
+
+/// Check that a solution agrees with a slow but obviously correct oracle on random inputs.
+///
+/// Panics with the smallest failing input found. See `find_failure` for how the inputs are
+/// generated and shrunk.
+pub fn check_differential<T, G, S, F, O>(
+    cases: u64,
+    max_size: usize,
+    generate: G,
+    shrink: S,
+    solution: F,
+    oracle: O,
+) where
+    T: PartialEq + Debug,
+    G: Fn(&mut Rng, usize) -> String,
+    S: Fn(&str) -> Vec<String>,
+    F: Fn(&str) -> T,
+    O: Fn(&str) -> T,
+{
+    if let Some(failure) = find_failure(cases, max_size, generate, shrink, solution, oracle) {
+        panic!(
+            "Solution and oracle disagree (seed {}, size {}) on input:\n{}\n\
+             solution: {:?}\noracle: {:?}",
+            failure.seed, failure.size, failure.input, failure.solution, failure.oracle
+        );
+    }
+}
@@ -0,0 +1,16 @@
+pub use answer::Answer;
+pub use answers::{check_examples, check_recorded_answers, read_example};
+pub use cli::Args;
+pub use differential::{check_differential, find_failure, without_each, Failure};
+pub use parse::{lines, Line, Lines, ParseError};
+pub use rng::Rng;
+pub use solution::Solution;
+
+mod answer;
+mod answers;
+mod cli;
+mod differential;
+pub mod parallel;
+mod parse;
+mod rng;
+mod solution;
@@ -0,0 +1,120 @@
+use std::cell::Cell;
+use std::ops::Range;
+use std::panic;
+use std::thread;
+
+/// Number of items each worker checks at a time while looking for the first match.
+const BLOCK: usize = 256;
+
+thread_local! {
+    static WORKERS: Cell<usize> = const { Cell::new(1) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let numbers: Vec<u64> = (0..1000).collect();
+        let expected: Vec<u64> = numbers.iter().map(|n| n * n).collect();
+        for workers in [1, 3, 8, 2000] {
+            set_workers(workers);
+            assert_eq!(map(&numbers, |n| n * n), expected);
+        }
+        assert!(map(&[] as &[u64], |n| *n).is_empty());
+    }
+
+    #[test]
+    fn test_find_first() {
+        for workers in [1, 4] {
+            set_workers(workers);
+            assert_eq!(find_first(0..100_000, |n| n * n > 5_000_000), Some(2237));
+            assert_eq!(find_first(10..20, |n| n < 10), None);
+            assert_eq!(find_first(5..6, |_| true), Some(5));
+        }
+    }
+
+    #[test]
+    fn test_workers_are_per_thread() {
+        set_workers(0);
+        assert_eq!(workers(), 1);
+        set_workers(6);
+        let other = thread::spawn(workers).join().unwrap();
+        assert_eq!((workers(), other), (6, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "odd number")]
+    fn test_panics_are_propagated() {
+        set_workers(4);
+        map(&[2, 4, 5, 6], |n| {
+            if n % 2 == 1 {
+                panic!("odd number");
+            }
+        });
+    }
+}
+
+/// Set the number of threads that the loops of the current thread get split into.
+///
+/// Loops are sequential by default (a single worker). Workers don't split their own loops any
+/// further, since the setting only applies to the thread that made it.
+pub fn set_workers(workers: usize) {
+    WORKERS.with(|w| w.set(workers.max(1)));
+}
+
+/// Return the number of threads that the loops of the current thread get split into.
+pub fn workers() -> usize {
+    WORKERS.with(|w| w.get())
+}
+
+/// Apply a function to every item and return the results in the same order.
+///
+/// The items are split in contiguous chunks, one for each worker.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = workers().min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let chunk_size = items.len().div_ceil(workers);
+    let results = thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().map(&f).collect::<Vec<R>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    results
+}
+
+/// Return the first number of the range that satisfies the predicate.
+///
+/// The workers check blocks of consecutive numbers, so they might check a few numbers past the
+/// first match before stopping.
+pub fn find_first<F>(range: Range<usize>, predicate: F) -> Option<usize>
+where
+    F: Fn(usize) -> bool + Sync,
+{
+    let workers = workers();
+    if workers == 1 {
+        return range.into_iter().find(|n| predicate(*n));
+    }
+    let block = workers * BLOCK;
+    for start in range.clone().step_by(block) {
+        let numbers: Vec<usize> = (start..(start + block).min(range.end)).collect();
+        let matches = map(&numbers, |n| predicate(*n));
+        if let Some(i) = matches.iter().position(|m| *m) {
+            return Some(numbers[i]);
+        }
+    }
+    None
+}
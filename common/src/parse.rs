@@ -0,0 +1,184 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let mut lines = lines(1, "3   4\n4   x\n");
+        let first = lines.next().unwrap();
+        let tokens: Vec<&str> = first.text.split_whitespace().collect();
+        assert_eq!(first.parse::<i32>(tokens[1]), Ok(4));
+
+        let second = lines.next().unwrap();
+        let tokens: Vec<&str> = second.text.split_whitespace().collect();
+        let error = second.parse::<i32>(tokens[1]).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (1, 2, 5));
+        assert_eq!(error.text, "x");
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 2, column 5: invalid value 'x'"
+        );
+
+        let error = lines.expect("a pair of numbers").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(
+            error.to_string(),
+            "day 1, line 3, column 1: missing a pair of numbers"
+        );
+    }
+
+    #[test]
+    fn test_error_at() {
+        let line = lines(6, "..#.\n.x..").nth(1).unwrap();
+        let error = line.error_at(1, "invalid character");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+        let error = line.missing("closing bracket");
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
+
+/// Error found while parsing the puzzle input of a day.
+///
+/// Lines and columns are numbered starting from one, as text editors do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(day: u32, line: usize, column: usize, text: &str, message: &str) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Single line of a puzzle input that knows its position, so it can build parse errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub day: u32,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Return the column where the token starts.
+    ///
+    /// The token must be a slice of the text of the line, otherwise the first column is returned.
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize).wrapping_sub(start);
+        if offset > self.text.len() {
+            return 1;
+        }
+        self.text[..offset].chars().count() + 1
+    }
+
+    /// Build an error that points to a token, which must be a slice of the text of the line.
+    pub fn error(&self, token: &str, message: &str) -> ParseError {
+        ParseError::new(self.day, self.number, self.column_of(token), token, message)
+    }
+
+    /// Build an error that points to the character at the given index (starting from zero).
+    pub fn error_at(&self, index: usize, message: &str) -> ParseError {
+        let text = match self.text.chars().nth(index) {
+            Some(c) => c.to_string(),
+            None => String::new(),
+        };
+        ParseError::new(self.day, self.number, index + 1, &text, message)
+    }
+
+    /// Build an error for something that should be at the end of the line, but it's missing.
+    pub fn missing(&self, what: &str) -> ParseError {
+        let column = self.text.chars().count() + 1;
+        ParseError::new(
+            self.day,
+            self.number,
+            column,
+            "",
+            &format!("missing {what}"),
+        )
+    }
+
+    /// Parse a token of the line, which must be a slice of its text.
+    pub fn parse<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        match token.trim().parse() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(self.error(token.trim(), "invalid value")),
+        }
+    }
+}
+
+/// Iterator over the lines of a puzzle input.
+pub struct Lines<'a> {
+    day: u32,
+    number: usize,
+    lines: std::str::Lines<'a>,
+}
+
+impl<'a> Lines<'a> {
+    /// Return the next line, or an error if the input has ended.
+    pub fn expect(&mut self, what: &str) -> Result<Line<'a>, ParseError> {
+        match self.next() {
+            Some(line) => Ok(line),
+            None => Err(ParseError::new(
+                self.day,
+                self.number + 1,
+                1,
+                "",
+                &format!("missing {what}"),
+            )),
+        }
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(Line {
+            day: self.day,
+            number: self.number,
+            text,
+        })
+    }
+}
+
+/// Iterate over the lines of the puzzle input of a given day.
+pub fn lines(day: u32, input: &str) -> Lines<'_> {
+    Lines {
+        day,
+        number: 0,
+        lines: input.lines(),
+    }
+}
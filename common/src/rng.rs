@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let values_a: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+        let values_b: Vec<u64> = (0..5).map(|_| b.next_u64()).collect();
+        let values_c: Vec<u64> = (0..5).map(|_| c.next_u64()).collect();
+        assert_eq!(values_a, values_b);
+        assert_ne!(values_a, values_c);
+    }
+
+    #[test]
+    fn test_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let value = rng.range(-3, 4);
+            assert!((-3..4).contains(&value));
+            assert!(rng.below(10) < 10);
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+        // Every value in a small range should show up
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            seen[rng.below(6) as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(1);
+        let mut values: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut values);
+        assert_ne!(values, (0..50).collect::<Vec<u32>>());
+        values.sort();
+        assert_eq!(values, (0..50).collect::<Vec<u32>>());
+        assert!(values.contains(rng.choose(&values)));
+    }
+}
+
+/// Small deterministic pseudo-random number generator (SplitMix64).
+///
+/// It's meant for generating synthetic puzzle inputs: the same seed always produces the same
+/// numbers on every platform. It's not suitable for cryptography.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Return a number in `[0, n)`, where `n` must be positive.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Return a number in `[low, high)`, where `high` must be greater than `low`.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low) as u64) as i64
+    }
+
+    /// Return a number in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// Return true with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// Return a random element of a non-empty slice.
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> &'a T {
+        &values[self.below(values.len() as u64) as usize]
+    }
+
+    /// Shuffle a slice in place (Fisher-Yates).
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            values.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use crate::{Answer, ParseError};
+
+/// Solution to the puzzle of a single day.
+///
+/// The input text is parsed only once, and the parsed input is shared by both parts of the
+/// puzzle. Days that don't have a second part can rely on the default implementation of
+/// `part_two`.
+pub trait Solution {
+    /// Parsed puzzle input.
+    type Input;
+
+    /// Parse the puzzle input from its text.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Solve the first part of the puzzle.
+    fn part_one(input: &Self::Input) -> Answer;
+
+    /// Solve the second part of the puzzle.
+    fn part_two(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
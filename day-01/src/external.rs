@@ -0,0 +1,320 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{self, AtomicUsize};
+
+use common::Line;
+
+use crate::{read_line, DAY};
+
+/// Number of distinct location IDs of each list that are kept in memory by default.
+pub const DEFAULT_CAPACITY: usize = 1 << 16;
+
+/// Maximum number of runs that get merged at the same time, to bound the number of open files.
+const MAX_OPEN_RUNS: usize = 64;
+
+/// Number of times the lists were counted so far, which keeps apart the runs of concurrent calls.
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::{parse, solve_part1, solve_part2};
+    use common::Rng;
+    use std::env;
+    use std::thread;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("day-01-{name}-{}", process::id()))
+    }
+
+    #[test]
+    fn test_external_matches_in_memory() {
+        let dir = temp_dir("external");
+        let input = generate(&mut Rng::new(1), 2000);
+        let lists = parse(&input).unwrap();
+        // A tiny table forces many runs for each list
+        for capacity in [7, 100, DEFAULT_CAPACITY] {
+            let distance = total_distance(input.as_bytes(), &dir, capacity).unwrap();
+            assert_eq!(distance, solve_part1(&lists) as i64);
+            let score = similarity_score(input.as_bytes(), &dir, capacity).unwrap();
+            assert_eq!(score, solve_part2(&lists) as i64);
+        }
+        // Every run is removed once the lists are merged
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_calls() {
+        // Calls sharing the directory don't overwrite the runs of each other
+        let dir = temp_dir("concurrent");
+        let inputs: Vec<String> = (0..4)
+            .map(|seed| generate(&mut Rng::new(seed), 500))
+            .collect();
+        let distances: Vec<i64> = thread::scope(|scope| {
+            let handles: Vec<_> = inputs
+                .iter()
+                .map(|input| scope.spawn(|| total_distance(input.as_bytes(), &dir, 7).unwrap()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        for (input, distance) in inputs.iter().zip(distances) {
+            assert_eq!(distance, solve_part1(&parse(input).unwrap()) as i64);
+        }
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_errors() {
+        let dir = temp_dir("errors");
+        let error = total_distance("1 2\n3 4\n5\n".as_bytes(), &dir, 1).unwrap_err();
+        assert!(error.to_string().contains("line 3"));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Counts of the location IDs of a list.
+///
+/// The counts are kept in a table with a bounded number of IDs. Whenever it gets full, the counts
+/// are sorted by ID and spilled to a new run file.
+struct Counter<'a> {
+    dir: &'a Path,
+    /// Start of the names of the run files, unique to each counter
+    prefix: String,
+    capacity: usize,
+    table: HashMap<i32, u64>,
+    runs: Vec<PathBuf>,
+    created: usize,
+}
+
+impl<'a> Counter<'a> {
+    fn new(dir: &'a Path, prefix: String, capacity: usize) -> Self {
+        Self {
+            dir,
+            prefix,
+            capacity: capacity.max(1),
+            table: HashMap::new(),
+            runs: vec![],
+            created: 0,
+        }
+    }
+
+    fn add(&mut self, id: i32) -> io::Result<()> {
+        if self.table.len() == self.capacity && !self.table.contains_key(&id) {
+            self.spill()?;
+        }
+        *self.table.entry(id).or_insert(0) += 1;
+        Ok(())
+    }
+
+    /// Create a new run file, which gets removed along with the counter.
+    fn create_run(&mut self) -> io::Result<BufWriter<File>> {
+        let fname = format!("{}-{}.run", self.prefix, self.created);
+        let path = self.dir.join(fname);
+        self.created += 1;
+        self.runs.push(path.clone());
+        Ok(BufWriter::new(File::create(path)?))
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        let mut counts: Vec<(i32, u64)> = self.table.drain().collect();
+        counts.sort_unstable();
+        let mut writer = self.create_run()?;
+        for (id, count) in counts {
+            write_record(&mut writer, id, count)?;
+        }
+        writer.flush()
+    }
+
+    /// Spill the remaining counts and merge all the runs.
+    fn finish(mut self) -> io::Result<Merge> {
+        if !self.table.is_empty() {
+            self.spill()?;
+        }
+        // Merge the oldest runs into new ones until all of them can be open at the same time
+        while self.runs.len() > MAX_OPEN_RUNS {
+            let mut merge = Merge::new(self.runs.drain(..MAX_OPEN_RUNS).collect())?;
+            let mut writer = self.create_run()?;
+            while let Some((id, count)) = merge.next()? {
+                write_record(&mut writer, id, count)?;
+            }
+            writer.flush()?;
+        }
+        Merge::new(mem::take(&mut self.runs))
+    }
+}
+
+impl Drop for Counter<'_> {
+    fn drop(&mut self) {
+        for run in self.runs.iter() {
+            let _ = fs::remove_file(run);
+        }
+    }
+}
+
+fn write_record(writer: &mut impl Write, id: i32, count: u64) -> io::Result<()> {
+    writer.write_all(&id.to_le_bytes())?;
+    writer.write_all(&count.to_le_bytes())
+}
+
+/// Read the next ID and its count from a run, or `None` if the run has ended.
+fn read_record(reader: &mut impl Read) -> io::Result<Option<(i32, u64)>> {
+    let mut record = [0; 12];
+    match reader.read_exact(&mut record) {
+        Ok(()) => {
+            let (id, count) = record.split_at(4);
+            Ok(Some((
+                i32::from_le_bytes(id.try_into().unwrap()),
+                u64::from_le_bytes(count.try_into().unwrap()),
+            )))
+        }
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// K-way merge of the sorted runs of a list, which removes them once it's dropped.
+struct Merge {
+    runs: Vec<PathBuf>,
+    readers: Vec<BufReader<File>>,
+    heap: BinaryHeap<Reverse<(i32, u64, usize)>>,
+}
+
+impl Merge {
+    fn new(runs: Vec<PathBuf>) -> io::Result<Self> {
+        let mut merge = Self {
+            runs,
+            readers: vec![],
+            heap: BinaryHeap::new(),
+        };
+        for i in 0..merge.runs.len() {
+            merge
+                .readers
+                .push(BufReader::new(File::open(&merge.runs[i])?));
+            merge.advance(i)?;
+        }
+        Ok(merge)
+    }
+
+    /// Push the next record of a run to the heap.
+    fn advance(&mut self, run: usize) -> io::Result<()> {
+        if let Some((id, count)) = read_record(&mut self.readers[run])? {
+            self.heap.push(Reverse((id, count, run)));
+        }
+        Ok(())
+    }
+
+    /// Return the next ID in increasing order, along with its count in all the runs.
+    fn next(&mut self) -> io::Result<Option<(i32, u64)>> {
+        let Some(Reverse((id, mut count, run))) = self.heap.pop() else {
+            return Ok(None);
+        };
+        self.advance(run)?;
+        while let Some(Reverse((other_id, other_count, other_run))) = self.heap.peek().copied() {
+            if other_id != id {
+                break;
+            }
+            self.heap.pop();
+            count += other_count;
+            self.advance(other_run)?;
+        }
+        Ok(Some((id, count)))
+    }
+}
+
+impl Drop for Merge {
+    fn drop(&mut self) {
+        for run in self.runs.iter() {
+            let _ = fs::remove_file(run);
+        }
+    }
+}
+
+/// Count the IDs of the first two lists of the input, reading it line by line.
+fn count_lists(
+    reader: impl BufRead,
+    dir: &Path,
+    capacity: usize,
+) -> Result<(Merge, Merge), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    let call = CALLS.fetch_add(1, atomic::Ordering::Relaxed);
+    let prefix = format!("{}-{call}", process::id());
+    let mut left = Counter::new(dir, format!("{prefix}-left"), capacity);
+    let mut right = Counter::new(dir, format!("{prefix}-right"), capacity);
+    let mut n_lists = None;
+    for (i, text) in reader.lines().enumerate() {
+        let text = text?;
+        let line = Line {
+            day: DAY,
+            number: i + 1,
+            text: &text,
+        };
+        let ids = read_line(&line, n_lists)?;
+        n_lists = Some(ids.len());
+        left.add(ids[0])?;
+        right.add(ids[1])?;
+    }
+    Ok((left.finish()?, right.finish()?))
+}
+
+/// Compute the total distance between the first two lists with bounded memory.
+///
+/// Only `capacity` distinct IDs of each list are kept in memory, while the rest of them go to
+/// temporary run files in `dir`.
+pub fn total_distance(
+    reader: impl BufRead,
+    dir: &Path,
+    capacity: usize,
+) -> Result<i64, Box<dyn Error>> {
+    let (mut left, mut right) = count_lists(reader, dir, capacity)?;
+    let mut total = 0;
+    let (mut a, mut b) = (left.next()?, right.next()?);
+    // Pair the smallest remaining IDs of both lists as many times as both of them are left
+    while let (Some((id_a, count_a)), Some((id_b, count_b))) = (a, b) {
+        let pairs = count_a.min(count_b);
+        total += (id_a as i64 - id_b as i64).abs() * pairs as i64;
+        a = match count_a - pairs {
+            0 => left.next()?,
+            rest => Some((id_a, rest)),
+        };
+        b = match count_b - pairs {
+            0 => right.next()?,
+            rest => Some((id_b, rest)),
+        };
+    }
+    Ok(total)
+}
+
+/// Compute the similarity score of the first two lists with bounded memory.
+///
+/// See `total_distance` for how the lists are kept on disk.
+pub fn similarity_score(
+    reader: impl BufRead,
+    dir: &Path,
+    capacity: usize,
+) -> Result<i64, Box<dyn Error>> {
+    let (mut left, mut right) = count_lists(reader, dir, capacity)?;
+    let mut score = 0;
+    let (mut a, mut b) = (left.next()?, right.next()?);
+    while let (Some((id_a, count_a)), Some((id_b, count_b))) = (a, b) {
+        match id_a.cmp(&id_b) {
+            Ordering::Less => a = left.next()?,
+            Ordering::Greater => b = right.next()?,
+            Ordering::Equal => {
+                score += id_a as i64 * count_a as i64 * count_b as i64;
+                a = left.next()?;
+                b = right.next()?;
+            }
+        }
+    }
+    Ok(score)
+}
@@ -0,0 +1,33 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 100);
+        let lists = parse(&input).unwrap();
+        assert_eq!(lists[0].len(), 100);
+        solve_part1(&lists);
+        assert!(solve_part2(&lists) > 0);
+    }
+}
+
+/// Generate a puzzle input with `size` pairs of location IDs.
+///
+/// About half of the IDs in the right list are taken from the left one, so the similarity score
+/// isn't trivially zero.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<i64> = (0..size).map(|_| rng.range(10000, 100000)).collect();
+    let mut input = String::new();
+    for value in left.iter() {
+        let right = match rng.chance(0.5) {
+            true => *rng.choose(&left),
+            false => rng.range(10000, 100000),
+        };
+        input.push_str(&format!("{value}   {right}\n"));
+    }
+    input
+}
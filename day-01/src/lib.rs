@@ -0,0 +1,245 @@
+use common::{lines, Answer, Line, ParseError, Solution};
+use std::collections::HashMap;
+use std::iter;
+
+pub mod external;
+pub mod generate;
+
+const DAY: u32 = 1;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        distance_matrix, format_matrix, parse, similarity_matrix, solve_part1, solve_part2, Day01,
+        EXAMPLES,
+    };
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_part1() {
+        let input = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&input);
+        assert_eq!(result, 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse(EXAMPLE).unwrap();
+        let result = solve_part2(&input);
+        assert_eq!(result, 31);
+    }
+
+    const EXAMPLE_THREE_COLUMNS: &str = "\
+3   4   3
+4   3   1
+2   5   2
+1   3   3
+3   9   5
+3   3   3
+";
+
+    #[test]
+    fn test_parse_columns() {
+        let lists = parse(EXAMPLE_THREE_COLUMNS).unwrap();
+        assert_eq!(lists.len(), 3);
+        assert_eq!(lists[2], vec![3, 1, 2, 3, 5, 3]);
+        assert_eq!(solve_part1(&lists), 11);
+        assert_eq!(solve_part2(&lists), 31);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("1 2 3\n4 5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = parse("1 2\n4 5 6\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert!(parse("1\n2\n").is_err());
+    }
+
+    #[test]
+    fn test_matrices() {
+        let lists = parse(EXAMPLE_THREE_COLUMNS).unwrap();
+        let distances = distance_matrix(&lists);
+        assert_eq!(
+            distances,
+            vec![vec![0, 11, 1], vec![11, 0, 10], vec![1, 10, 0]]
+        );
+        let similarities = similarity_matrix(&lists);
+        assert_eq!(similarities[0][1], 31);
+        assert_eq!(similarities[1][2], 3 * 3 + 3 * 3 + 5 + 3 * 3);
+        assert_eq!(similarities[2][1], 3 * 3 + 5 + 3 * 3 + 3 * 3);
+        assert_eq!(similarities[0][2], 3 * 3 * 3 + 2 + 1);
+        let expected = "     1   2   3
+1    0  11   1
+2   11   0  10
+3    1  10   0
+";
+        assert_eq!(format_matrix(&distances), expected);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day01>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["11", "31"]);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let lists = parse("").unwrap();
+        assert_eq!(lists, vec![vec![], vec![]]);
+        assert_eq!(solve_part1(&lists), 0);
+        assert_eq!(solve_part2(&lists), 0);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Lists of location IDs, one for each column of the input.
+pub type Lists = Vec<Vec<i32>>;
+
+/// Read the location IDs in a line, which must have `n_lists` of them if given, or at least two.
+fn read_line(line: &Line, n_lists: Option<usize>) -> Result<Vec<i32>, ParseError> {
+    let values: Vec<&str> = line.text.split_whitespace().collect();
+    let n_lists = n_lists.unwrap_or(values.len().max(2));
+    if values.len() < n_lists {
+        return Err(line.missing("location ID"));
+    }
+    if let Some(extra) = values.get(n_lists) {
+        return Err(line.error(extra, "unexpected value"));
+    }
+    let ids = values.iter().map(|value| line.parse(value)).collect();
+    ids
+}
+
+/// Parse the lists of location IDs.
+///
+/// The first line sets the number of lists (at least two), and every other line must have the
+/// same number of values, so all lists have the same length. An empty input holds two empty lists.
+pub fn parse(content: &str) -> Result<Lists, ParseError> {
+    let mut lists: Lists = vec![];
+    for line in lines(DAY, content) {
+        let n_lists = (!lists.is_empty()).then_some(lists.len());
+        let ids = read_line(&line, n_lists)?;
+        if lists.is_empty() {
+            lists = vec![vec![]; ids.len()];
+        }
+        for (list, id) in iter::zip(lists.iter_mut(), ids) {
+            list.push(id);
+        }
+    }
+    if lists.is_empty() {
+        lists = vec![vec![]; 2];
+    }
+    return Ok(lists);
+}
+
+pub fn solve_part1(lists: &Lists) -> i32 {
+    total_distance(&lists[0], &lists[1])
+}
+
+pub fn solve_part2(lists: &Lists) -> i32 {
+    similarity_score(&lists[0], &lists[1])
+}
+
+/// Compute the total distance between every pair of lists.
+pub fn distance_matrix(lists: &Lists) -> Vec<Vec<i32>> {
+    let matrix = lists
+        .iter()
+        .map(|a| lists.iter().map(|b| total_distance(a, b)).collect())
+        .collect();
+    matrix
+}
+
+/// Compute the similarity score of every pair of lists.
+///
+/// The score isn't symmetric: row `i` and column `j` hold the score of the IDs in list `i`
+/// counted on list `j`.
+pub fn similarity_matrix(lists: &Lists) -> Vec<Vec<i32>> {
+    let matrix = lists
+        .iter()
+        .map(|a| lists.iter().map(|b| similarity_score(a, b)).collect())
+        .collect();
+    matrix
+}
+
+/// Format a matrix as a table, with the (one-based) number of each list in the headers.
+pub fn format_matrix(matrix: &[Vec<i32>]) -> String {
+    let width = matrix
+        .iter()
+        .flatten()
+        .map(|value| value.to_string().len())
+        .chain([matrix.len().to_string().len()])
+        .max()
+        .unwrap_or(1);
+    let mut table = format!("{:>width$}", "");
+    for j in 1..=matrix.len() {
+        table.push_str(&format!("  {j:>width$}"));
+    }
+    table.push('\n');
+    for (i, row) in matrix.iter().enumerate() {
+        table.push_str(&format!("{:<width$}", i + 1));
+        for value in row {
+            table.push_str(&format!("  {value:>width$}"));
+        }
+        table.push('\n');
+    }
+    table
+}
+
+fn total_distance(left: &[i32], right: &[i32]) -> i32 {
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    left.sort();
+    right.sort();
+    let result = iter::zip(left, right).map(|(x, y)| (x - y).abs()).sum();
+    return result;
+}
+
+fn similarity_score(left: &[i32], right: &[i32]) -> i32 {
+    let counts: HashMap<i32, i32> = {
+        let mut counts = HashMap::new();
+        for element in right.iter() {
+            counts.entry(*element).and_modify(|x| *x += 1).or_insert(1);
+        }
+        counts
+    };
+    let result = left
+        .iter()
+        .map(|x| match counts.get(x) {
+            Some(v) => x * v,
+            None => 0,
+        })
+        .sum();
+    result
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Lists;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
@@ -1,75 +1,67 @@
-use std::collections::HashMap;
-use std::fs;
-use std::iter;
+use common::Args;
+use day_01::external::{self, DEFAULT_CAPACITY};
+use day_01::{
+    distance_matrix, format_matrix, parse, similarity_matrix, solve_part1, solve_part2, EXAMPLES,
+};
+use std::error::Error;
+use std::path::Path;
+use std::process;
 
-#[cfg(test)]
-mod tests {
-    use crate::{solve_part1, solve_part2};
-
-    #[test]
-    fn test_part1() {
-        let fname = "data/test_input";
-        let result = solve_part1(fname.to_string());
-        assert_eq!(result, 11);
+/// Solve the first two lists of the input with bounded memory, spilling them into `dir`.
+fn solve_external(args: &Args, dir: &Path) -> Result<(), Box<dyn Error>> {
+    if args.solves(1) {
+        let input = args.open_input(&EXAMPLES, 1)?;
+        let solution = external::total_distance(input, dir, DEFAULT_CAPACITY)?;
+        println!("Solution to part 1: {solution}");
     }
-
-    #[test]
-    fn test_part2() {
-        let fname = "data/test_input";
-        let result = solve_part2(fname.to_string());
-        assert_eq!(result, 31);
+    if args.solves(2) {
+        let input = args.open_input(&EXAMPLES, 2)?;
+        let solution = external::similarity_score(input, dir, DEFAULT_CAPACITY)?;
+        println!("Solution to part 2: {solution}");
     }
+    Ok(())
 }
 
-fn read_file(fname: String) -> (Vec<i32>, Vec<i32>) {
-    let content = fs::read_to_string(fname).expect("Couldn't read file");
-    let (left, right) = {
-        let mut left: Vec<i32> = Vec::new();
-        let mut right: Vec<i32> = Vec::new();
-        for line in content.lines() {
-            let line_vec: Vec<i32> = line
-                .split_whitespace()
-                .map(|x| x.parse().expect("error"))
-                .collect();
-            left.push(line_vec[0]);
-            right.push(line_vec[1]);
+fn main() {
+    let args = Args::from_env_with(2, &["--external DIR"]);
+    if let Some(dir) = args.option("--external") {
+        if let Err(e) = solve_external(&args, Path::new(dir)) {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+        return;
+    }
+    let content = match args.read_input(&EXAMPLES, args.first_part()) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Couldn't read input: {e}");
+            process::exit(1);
         }
-        (left, right)
     };
-    return (left, right);
-}
-
-fn solve_part1(fname: String) -> i32 {
-    let (mut left, mut right) = read_file(fname);
-    left.sort();
-    right.sort();
-    let result = iter::zip(left, right).map(|(x, y)| (x - y).abs()).sum();
-    return result;
-}
-
-fn solve_part2(fname: String) -> i32 {
-    let (left, right) = read_file(fname);
-    let counts: HashMap<i32, i32> = {
-        let mut counts = HashMap::new();
-        for element in right {
-            counts.entry(element).and_modify(|x| *x += 1).or_insert(1);
+    let lists = match parse(&content) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
         }
-        counts
     };
-    let result = left
-        .iter()
-        .map(|x| match counts.get(&x) {
-            Some(v) => x * v,
-            None => 0,
-        })
-        .sum();
-    result
-}
-
-fn main() {
-    let fname = "data/input";
-    let solution = solve_part1(fname.to_string());
-    println!("Solution to part 1: {solution}");
-    let solution = solve_part2(fname.to_string());
-    println!("Solution to part 2: {solution}");
+    if args.solves(1) {
+        let solution = solve_part1(&lists);
+        println!("Solution to part 1: {solution}");
+        // Compare every pair of lists when there are more than two of them
+        if lists.len() > 2 {
+            let matrix = format_matrix(&distance_matrix(&lists));
+            println!("Total distance between each pair of lists:\n{matrix}");
+        }
+    }
+    if args.solves(2) {
+        let solution = solve_part2(&lists);
+        println!("Solution to part 2: {solution}");
+        if lists.len() > 2 {
+            let matrix = format_matrix(&similarity_matrix(&lists));
+            println!(
+                "Similarity score of each list (rows) on each other list (columns):\n{matrix}"
+            );
+        }
+    }
 }
@@ -0,0 +1,76 @@
+use crate::{fewest_removals, first_violation, SafetyPolicy, Violation};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnose() {
+        let policy = SafetyPolicy::PART_TWO;
+        let diagnosis = diagnose(&[7, 6, 4, 2, 1], &policy);
+        assert_eq!(diagnosis.violation, None);
+        assert!(diagnosis.is_safe());
+        let diagnosis = diagnose(&[1, 2, 7, 8, 9], &policy);
+        assert_eq!(diagnosis.violation, Some((2, Violation::StepTooLarge)));
+        assert_eq!(diagnosis.removals, None);
+        let diagnosis = diagnose(&[1, 3, 2, 4, 5], &policy);
+        assert_eq!(diagnosis.violation, Some((2, Violation::DirectionFlip)));
+        assert_eq!(diagnosis.removals, Some(vec![1]));
+        let diagnosis = diagnose(&[8, 6, 4, 4, 1], &policy);
+        assert_eq!(diagnosis.violation, Some((3, Violation::ZeroStep)));
+        assert_eq!(diagnosis.removals, Some(vec![2]));
+    }
+
+    #[test]
+    fn test_display() {
+        let report = [1, 3, 2, 4, 5];
+        let diagnosis = diagnose(&report, &SafetyPolicy::PART_ONE);
+        let expected = "direction flip from 3 to 2 at level 3, too many levels to remove";
+        assert_eq!(diagnosis.describe(&report), expected);
+        let diagnosis = diagnose(&report, &SafetyPolicy::PART_TWO);
+        let expected = "direction flip from 3 to 2 at level 3, safe without level 2 (3)";
+        assert_eq!(diagnosis.describe(&report), expected);
+    }
+}
+
+/// Explanation of why a report is unsafe, and how it can be made safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Index of the first level whose step from the previous one breaks the rules, and how
+    pub violation: Option<(usize, Violation)>,
+    /// Indices of the fewest levels to remove to make the report safe, if the tolerance allows it
+    pub removals: Option<Vec<usize>>,
+}
+
+/// Explain whether the report is safe under the given policy.
+pub fn diagnose(report: &[i32], policy: &SafetyPolicy) -> Diagnosis {
+    Diagnosis {
+        violation: first_violation(report, policy),
+        removals: fewest_removals(report, policy),
+    }
+}
+
+impl Diagnosis {
+    pub fn is_safe(&self) -> bool {
+        self.removals.is_some()
+    }
+
+    /// Describe the diagnosis of the given report, counting its levels from one.
+    pub fn describe(&self, report: &[i32]) -> String {
+        let Some((i, violation)) = self.violation else {
+            return "safe".to_string();
+        };
+        let (from, to) = (report[i - 1], report[i]);
+        let fix = match &self.removals {
+            Some(removals) => {
+                let levels: Vec<String> = removals
+                    .iter()
+                    .map(|r| format!("level {} ({})", r + 1, report[*r]))
+                    .collect();
+                format!("safe without {}", levels.join(", "))
+            }
+            None => "too many levels to remove".to_string(),
+        };
+        format!("{violation} from {from} to {to} at level {}, {fix}", i + 1)
+    }
+}
@@ -0,0 +1,50 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 200);
+        let reports = parse(&input).unwrap();
+        assert_eq!(reports.len(), 200);
+        let safe = solve_part1(&reports);
+        let safe_with_tolerance = solve_part2(&reports);
+        assert!(0 < safe && safe < safe_with_tolerance && safe_with_tolerance < 200);
+    }
+}
+
+/// Generate a puzzle input with `size` reports.
+///
+/// Reports start as safe sequences of levels, and some of them get one or more of their levels
+/// altered so they become unsafe.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let length = rng.range(5, 9) as usize;
+        let sign = match rng.chance(0.5) {
+            true => 1,
+            false => -1,
+        };
+        let mut levels = vec![rng.range(20, 80)];
+        for _ in 1..length {
+            let last = levels[levels.len() - 1];
+            levels.push(last + sign * rng.range(1, 4));
+        }
+        let n_errors = match rng.below(3) {
+            0 => 0,
+            1 => 1,
+            _ => rng.range(2, 4) as usize,
+        };
+        for _ in 0..n_errors {
+            let i = rng.below(length as u64) as usize;
+            levels[i] += rng.range(-5, 6);
+        }
+        let levels: Vec<String> = levels.iter().map(|l| l.max(&1).to_string()).collect();
+        input.push_str(&levels.join(" "));
+        input.push('\n');
+    }
+    input
+}
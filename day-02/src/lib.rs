@@ -0,0 +1,305 @@
+use common::{lines, Answer, ParseError, Solution};
+pub use diagnosis::{diagnose, Diagnosis};
+pub use policy::{SafetyPolicy, Violation};
+
+mod diagnosis;
+pub mod generate;
+mod policy;
+
+const DAY: u32 = 2;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_safe, fewest_removals, is_valid, is_valid_with_k_removals, parse, solve_part1,
+        solve_part2, Day02, SafetyPolicy, EXAMPLES,
+    };
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    const CUSTOM_EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+86 85 86 89 92 94 97
+1 2 6 3 4 5 6
+";
+
+    #[test]
+    fn test_part1() {
+        let reports = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&reports);
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let reports = parse(EXAMPLE).unwrap();
+        let result = solve_part2(&reports);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part2_custom_file() {
+        let reports = parse(CUSTOM_EXAMPLE).unwrap();
+        let result = solve_part2(&reports);
+        assert_eq!(result, 6);
+    }
+
+    /// Check every way of removing up to `k` levels.
+    fn brute_force(report: &[i32], k: usize) -> bool {
+        if is_valid(report, &SafetyPolicy::PART_ONE) {
+            return true;
+        }
+        let valid = k > 0
+            && (0..report.len()).any(|i| {
+                let mut smaller = report.to_vec();
+                smaller.remove(i);
+                brute_force(&smaller, k - 1)
+            });
+        valid
+    }
+
+    #[test]
+    fn test_k_removals() {
+        let report = [1, 5, 2, 9, 3, 4, 4];
+        assert!(!is_valid_with_k_removals(&report, 2));
+        assert!(is_valid_with_k_removals(&report, 3));
+        assert!(is_valid_with_k_removals(&[7], 0));
+        assert!(is_valid_with_k_removals(&[7, 7], 1));
+        assert!(!is_valid_with_k_removals(&[7, 7], 0));
+        let reports = parse(CUSTOM_EXAMPLE).unwrap();
+        for report in reports.iter() {
+            for k in 0..4 {
+                assert_eq!(is_valid_with_k_removals(report, k), brute_force(report, k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_k_removals_generated() {
+        let input = crate::generate::generate(&mut common::Rng::new(2), 300);
+        for report in parse(&input).unwrap().iter() {
+            for k in 0..3 {
+                assert_eq!(is_valid_with_k_removals(report, k), brute_force(report, k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fewest_removals() {
+        let input = crate::generate::generate(&mut common::Rng::new(3), 300);
+        for report in parse(&input).unwrap().iter() {
+            for k in 0..3 {
+                let policy = SafetyPolicy {
+                    tolerance: k,
+                    ..SafetyPolicy::PART_ONE
+                };
+                let Some(removals) = fewest_removals(report, &policy) else {
+                    assert!(!brute_force(report, k));
+                    continue;
+                };
+                // No fewer removals work, and the ones returned do
+                assert!(removals.is_empty() || !brute_force(report, removals.len() - 1));
+                let kept: Vec<i32> = (0..report.len())
+                    .filter(|i| !removals.contains(i))
+                    .map(|i| report[i])
+                    .collect();
+                assert!(is_valid(&kept, &policy));
+            }
+        }
+    }
+
+    #[test]
+    fn test_policies() {
+        let reports = parse(CUSTOM_EXAMPLE).unwrap();
+        // A step of four makes the third report safe
+        let policy = SafetyPolicy::parse("max_step = 4\n").unwrap();
+        assert_eq!(count_safe(&reports, &policy), 3);
+        // The fifth report is safe with a plateau, even without removing the repeated level
+        let policy = SafetyPolicy::parse("plateaus = true\ntolerance = 1\n").unwrap();
+        assert_eq!(count_safe(&reports, &policy), 6);
+        let policy = SafetyPolicy::parse("monotonic = false\n").unwrap();
+        assert_eq!(count_safe(&reports, &policy), 4);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day02>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["2", "4"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Return the first level whose step from the previous one breaks the policy, and how.
+fn first_violation(report: &[i32], policy: &SafetyPolicy) -> Option<(usize, Violation)> {
+    let mut prev_diff: Option<i32> = None;
+    for i in 1..report.len() {
+        if let Err(violation) = policy.check_step(report[i - 1], report[i], &mut prev_diff) {
+            return Some((i, violation));
+        }
+    }
+    return None;
+}
+
+/// Return true if the report is safe without removing any level.
+fn is_valid(report: &[i32], policy: &SafetyPolicy) -> bool {
+    first_violation(report, policy).is_none()
+}
+
+/// Directions a safe report can go in: none yet (a single level), increasing or decreasing.
+const DIRECTIONS: [Option<i32>; 3] = [None, Some(1), Some(-1)];
+
+fn direction_index(diff: Option<i32>) -> usize {
+    match diff.map(|d| d.signum()) {
+        None => 0,
+        Some(1) => 1,
+        Some(_) => 2,
+    }
+}
+
+/// Fewest removals that end a safe report at a level going in a direction, along with the
+/// previous level kept and its direction.
+type Path = (usize, Option<(usize, usize)>);
+
+/// Return true if the report can be made safe by removing at most `k` of its levels.
+pub fn is_valid_with_k_removals(report: &[i32], k: usize) -> bool {
+    let policy = SafetyPolicy {
+        tolerance: k,
+        ..SafetyPolicy::PART_ONE
+    };
+    is_safe(report, &policy)
+}
+
+/// Return true if the report is safe under the given policy.
+pub fn is_safe(report: &[i32], policy: &SafetyPolicy) -> bool {
+    let safe = report.len() <= policy.tolerance + 1
+        || is_valid(report, policy)
+        || fewest_removals(report, policy).is_some();
+    safe
+}
+
+/// Return the fewest levels to remove to make the report safe, or `None` if there are more of
+/// them than the tolerance `k` of the policy.
+///
+/// Instead of trying every removal allowed by the tolerance, find for each level the fewest
+/// removals that leave it as the last level of a safe report going in each direction. Only the
+/// `k` levels before it can be the previous level kept.
+pub fn fewest_removals(report: &[i32], policy: &SafetyPolicy) -> Option<Vec<usize>> {
+    let (n, k) = (report.len(), policy.tolerance);
+    if is_valid(report, policy) {
+        return Some(vec![]);
+    }
+    let mut best: Vec<[Option<Path>; DIRECTIONS.len()]> = vec![[None; DIRECTIONS.len()]; n];
+    for i in 0..n {
+        // Keep this level as the first one, removing all the previous ones
+        best[i][0] = Some((i, None));
+        for j in (i.saturating_sub(k + 1)..i).rev() {
+            let skipped = i - j - 1;
+            for (d, direction) in DIRECTIONS.iter().enumerate() {
+                let Some((removed, _)) = best[j][d] else {
+                    continue;
+                };
+                let mut diff = *direction;
+                if policy.is_step_valid(report[j], report[i], &mut diff) {
+                    let removed = removed + skipped;
+                    let next = &mut best[i][direction_index(diff)];
+                    if next.is_none_or(|(r, _)| removed < r) {
+                        *next = Some((removed, Some((j, d))));
+                    }
+                }
+            }
+        }
+    }
+    // Remove every level after the last one kept
+    let mut last = None;
+    for (i, levels) in best.iter().enumerate() {
+        for (d, level) in levels.iter().enumerate() {
+            if let Some((removed, _)) = level {
+                let removed = removed + n - 1 - i;
+                if removed <= k && last.is_none_or(|(r, _, _)| removed < r) {
+                    last = Some((removed, i, d));
+                }
+            }
+        }
+    }
+    let (_, mut i, mut d) = last?;
+    let mut kept = vec![false; n];
+    loop {
+        kept[i] = true;
+        match best[i][d] {
+            Some((_, Some((j, dj)))) => (i, d) = (j, dj),
+            _ => break,
+        }
+    }
+    let removals = (0..n).filter(|i| !kept[*i]).collect();
+    return Some(removals);
+}
+
+pub fn parse(content: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let mut reports = vec![];
+    for line in lines(DAY, content) {
+        let report = line
+            .text
+            .split_whitespace()
+            .map(|x| line.parse(x))
+            .collect::<Result<Vec<i32>, ParseError>>()?;
+        if report.is_empty() {
+            return Err(line.missing("levels"));
+        }
+        reports.push(report);
+    }
+    return Ok(reports);
+}
+
+/// Count the reports that are safe under the given policy.
+pub fn count_safe(reports: &[Vec<i32>], policy: &SafetyPolicy) -> i32 {
+    let mut result = 0;
+    for report in reports.iter() {
+        result += is_safe(report, policy) as i32;
+    }
+    return result;
+}
+
+pub fn solve_part1(reports: &[Vec<i32>]) -> i32 {
+    count_safe(reports, &SafetyPolicy::PART_ONE)
+}
+
+pub fn solve_part2(reports: &[Vec<i32>]) -> i32 {
+    count_safe(reports, &SafetyPolicy::PART_TWO)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
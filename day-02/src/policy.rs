@@ -0,0 +1,245 @@
+use std::fmt;
+
+use common::{lines, Line, ParseError};
+
+use crate::DAY;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = "\
+# Allow longer steps and a couple of bad levels
+max_step = 5
+plateaus = true
+
+tolerance = 2
+";
+        let policy = SafetyPolicy::parse(config).unwrap();
+        let expected = SafetyPolicy {
+            max_step: 5,
+            plateaus: true,
+            tolerance: 2,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert_eq!(policy, expected);
+        assert_eq!(SafetyPolicy::parse("").unwrap(), SafetyPolicy::PART_ONE);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = SafetyPolicy::parse("min_step = 1\nmax_steps = 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = SafetyPolicy::parse("monotonic = yes\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 13));
+        assert!(SafetyPolicy::parse("tolerance\n").is_err());
+        let error = SafetyPolicy::parse("min_step = 4\n\nmax_step = 3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 12));
+        assert_eq!(error.text, "3");
+        let error = SafetyPolicy::parse("min_step = 5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+        let error = SafetyPolicy::parse("max_step = 9\nmin_step = 0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 12));
+        assert_eq!(error.text, "0");
+    }
+
+    #[test]
+    fn test_steps() {
+        let policy = SafetyPolicy::PART_ONE;
+        let mut diff = None;
+        assert!(policy.is_step_valid(1, 3, &mut diff));
+        assert_eq!(diff, Some(2));
+        assert!(!policy.is_step_valid(3, 2, &mut diff));
+        assert!(!policy.is_step_valid(3, 3, &mut diff));
+        assert!(!policy.is_step_valid(3, 7, &mut diff));
+        let policy = SafetyPolicy {
+            monotonic: false,
+            plateaus: true,
+            ..SafetyPolicy::PART_ONE
+        };
+        assert!(policy.is_step_valid(3, 3, &mut diff));
+        assert_eq!(diff, Some(2));
+        assert!(policy.is_step_valid(3, 2, &mut diff));
+    }
+
+    #[test]
+    fn test_violations() {
+        let policy = SafetyPolicy {
+            min_step: 2,
+            ..SafetyPolicy::PART_ONE
+        };
+        let mut diff = Some(2);
+        assert_eq!(
+            policy.check_step(3, 2, &mut diff),
+            Err(Violation::DirectionFlip)
+        );
+        assert_eq!(policy.check_step(3, 3, &mut diff), Err(Violation::ZeroStep));
+        assert_eq!(
+            policy.check_step(3, 4, &mut diff),
+            Err(Violation::StepTooSmall)
+        );
+        assert_eq!(
+            policy.check_step(3, 7, &mut diff),
+            Err(Violation::StepTooLarge)
+        );
+        assert_eq!(diff, Some(2));
+        assert_eq!(policy.check_step(3, 6, &mut diff), Ok(()));
+        assert_eq!(diff, Some(3));
+    }
+}
+
+/// Ways the step between two adjacent levels can break a policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Violation {
+    /// The levels go the other way than the previous step
+    DirectionFlip,
+    /// The levels are equal, without allowing plateaus
+    ZeroStep,
+    /// The change is smaller than `min_step`
+    StepTooSmall,
+    /// The change is larger than `max_step`
+    StepTooLarge,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Violation::DirectionFlip => "direction flip",
+            Violation::ZeroStep => "zero step",
+            Violation::StepTooSmall => "step too small",
+            Violation::StepTooLarge => "step too large",
+        };
+        write!(f, "{text}")
+    }
+}
+
+/// Rules that decide whether a report is safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Smallest change between two adjacent levels
+    pub min_step: i32,
+    /// Largest change between two adjacent levels
+    pub max_step: i32,
+    /// Whether all the levels must be either increasing or decreasing
+    pub monotonic: bool,
+    /// Whether two adjacent levels can be equal, regardless of `min_step`
+    pub plateaus: bool,
+    /// Number of levels that can be removed to make a report safe
+    pub tolerance: usize,
+}
+
+impl SafetyPolicy {
+    /// Rules of the first part of the puzzle.
+    pub const PART_ONE: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        monotonic: true,
+        plateaus: false,
+        tolerance: 0,
+    };
+
+    /// Rules of the second part of the puzzle, where the Problem Dampener removes a bad level.
+    pub const PART_TWO: Self = Self {
+        tolerance: 1,
+        ..Self::PART_ONE
+    };
+
+    /// Parse a policy from lines like `max_step = 3`.
+    ///
+    /// Settings that are missing keep the values of the first part of the puzzle. Empty lines and
+    /// lines starting with `#` are ignored.
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut policy = Self::PART_ONE;
+        // Line and value of the steps that were set, to point at them if they conflict
+        let (mut min_step, mut max_step) = (None, None);
+        for line in lines(DAY, content) {
+            let text = line.text.trim();
+            if text.is_empty() || text.starts_with("#") {
+                continue;
+            }
+            let Some((key, value)) = text.split_once("=") else {
+                return Err(line.missing("'=' separator"));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "min_step" => {
+                    policy.min_step = line.parse(value)?;
+                    min_step = Some((line, value));
+                }
+                "max_step" => {
+                    policy.max_step = line.parse(value)?;
+                    max_step = Some((line, value));
+                }
+                "monotonic" => policy.monotonic = parse_bool(&line, value)?,
+                "plateaus" => policy.plateaus = parse_bool(&line, value)?,
+                "tolerance" => policy.tolerance = line.parse(value)?,
+                _ => return Err(line.error(key, "unknown setting")),
+            }
+        }
+        if policy.min_step < 1 || policy.max_step < policy.min_step {
+            let message = "steps should satisfy 1 <= min_step <= max_step";
+            // Both steps conflict when they were set, so point at the last one
+            let setting = match policy.min_step < 1 {
+                true => min_step,
+                false => [min_step, max_step]
+                    .into_iter()
+                    .flatten()
+                    .max_by_key(|(line, _)| line.number),
+            };
+            return Err(match setting {
+                Some((line, value)) => line.error(value, message),
+                None => ParseError::new(DAY, 1, 1, "", message),
+            });
+        }
+        Ok(policy)
+    }
+
+    /// Check the step between two adjacent levels, returning how it breaks the policy if it does.
+    ///
+    /// Takes the previous change of level, if any, which gets updated unless the level stays the
+    /// same.
+    pub fn check_step(
+        &self,
+        this: i32,
+        next: i32,
+        prev_diff: &mut Option<i32>,
+    ) -> Result<(), Violation> {
+        let diff = next - this;
+        if diff == 0 {
+            return match self.plateaus {
+                true => Ok(()),
+                false => Err(Violation::ZeroStep),
+            };
+        }
+        if let Some(x) = prev_diff {
+            if self.monotonic && diff.signum() != x.signum() {
+                return Err(Violation::DirectionFlip);
+            };
+        };
+        if diff.abs() < self.min_step {
+            return Err(Violation::StepTooSmall);
+        }
+        if diff.abs() > self.max_step {
+            return Err(Violation::StepTooLarge);
+        };
+        *prev_diff = Some(diff);
+        return Ok(());
+    }
+
+    /// Check if the step between two adjacent levels is valid.
+    ///
+    /// See `check_step` for how the previous change of level is used.
+    pub fn is_step_valid(&self, this: i32, next: i32, prev_diff: &mut Option<i32>) -> bool {
+        self.check_step(this, next, prev_diff).is_ok()
+    }
+}
+
+fn parse_bool(line: &Line, value: &str) -> Result<bool, ParseError> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(line.error(value, "expected true or false")),
+    }
+}
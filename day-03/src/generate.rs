@@ -0,0 +1,61 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 3000);
+        assert!(input.len() >= 3000);
+        let memory = parse(&input).unwrap();
+        assert!(solve_part1(&memory) > solve_part2(&memory));
+    }
+}
+
+const GARBAGE: &[u8] = b"!@#$%^&*()[]{}<>?/\\'+-,:; _~whatfromselectwhy";
+
+/// Generate a corrupted memory of (at least) `size` characters.
+///
+/// It mixes valid `mul(X,Y)` instructions with corrupted ones, `do()` and `don't()` instructions
+/// and random garbage, split in lines of about 3000 characters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut line_length = 0;
+    while input.len() < size {
+        let token = match rng.below(10) {
+            0..=2 => format!("mul({},{})", rng.range(1, 1000), rng.range(1, 1000)),
+            3 => {
+                // Corrupted instructions that should be ignored
+                let (x, y) = (rng.range(1, 1000), rng.range(1, 1000));
+                let options = [
+                    format!("mul({x}, {y})"),
+                    format!("mul[{x},{y}]"),
+                    format!("mul({x},{y}"),
+                    format!("mul ( {x},{y})"),
+                    format!("mul({x}{y})"),
+                ];
+                rng.choose(&options).to_string()
+            }
+            4 => match rng.chance(0.5) {
+                true => "do()".to_string(),
+                false => "don't()".to_string(),
+            },
+            _ => {
+                let length = rng.range(1, 8);
+                (0..length).map(|_| *rng.choose(GARBAGE) as char).collect()
+            }
+        };
+        line_length += token.len();
+        input.push_str(&token);
+        if line_length > 3000 {
+            input.push('\n');
+            line_length = 0;
+        }
+    }
+    if !input.ends_with('\n') {
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,177 @@
+use common::{Answer, ParseError, Solution};
+pub use tokenizer::{tokenize, Instruction, Token, Tokenizer};
+pub use trace::{render, trace, Step, Trace};
+
+pub mod generate;
+pub mod stream;
+mod tokenizer;
+mod trace;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input_2"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const EXAMPLE_2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn test_part1() {
+        let memory = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&memory);
+        assert_eq!(result, 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        let memory = parse(EXAMPLE_2).unwrap();
+        let result = solve_part2(&memory);
+        assert_eq!(result, 48);
+    }
+
+    #[test]
+    fn test_parse_and_execute() {
+        let result = parse_and_execute("ajlfasdmul(5,4)alksjf");
+        assert_eq!(result, 20);
+    }
+
+    #[test]
+    fn test_parse_and_execute_with_do() {
+        let result =
+            parse_and_execute_with_do("ajlfasdmul(5,4)alkdon't()sjmul(3,4)lajdon't()ajdmul(3,9)f");
+        assert_eq!(result, 20);
+    }
+
+    #[test]
+    fn test_large_sum() {
+        let memory = "mul(999,999)".repeat(3000);
+        assert_eq!(solve_part1(&memory), 999 * 999 * 3000);
+    }
+
+    #[test]
+    fn test_interpreter_state() {
+        let mut interpreter = Interpreter::new(true);
+        interpreter.execute(Instruction::Dont);
+        interpreter.execute(Instruction::Mul(2, 3));
+        assert!(!interpreter.is_enabled());
+        assert_eq!(interpreter.sum(), 0);
+        interpreter.execute(Instruction::Do);
+        interpreter.execute(Instruction::Mul(2, 3));
+        assert!(interpreter.is_enabled());
+        assert_eq!(interpreter.sum(), 6);
+        // Without conditionals, every multiplication runs
+        let mut interpreter = Interpreter::new(false);
+        interpreter.execute(Instruction::Dont);
+        interpreter.execute(Instruction::Mul(2, 3));
+        assert_eq!(interpreter.sum(), 6);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day03>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["161", "48"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Interpreter of the instructions of the corrupted memory.
+#[derive(Debug)]
+pub struct Interpreter {
+    /// Whether `do()` and `don't()` enable and disable the multiplications
+    conditionals: bool,
+    enabled: bool,
+    sum: i64,
+}
+
+impl Interpreter {
+    pub fn new(conditionals: bool) -> Self {
+        Self {
+            conditionals,
+            enabled: true,
+            sum: 0,
+        }
+    }
+
+    /// Return true if the next multiplication would be executed.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled || !self.conditionals
+    }
+
+    /// Return the sum of the multiplications executed so far.
+    pub fn sum(&self) -> i64 {
+        self.sum
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Mul(x, y) => {
+                if self.is_enabled() {
+                    self.sum += x as i64 * y as i64;
+                }
+            }
+            Instruction::Do => self.enabled = true,
+            Instruction::Dont => self.enabled = false,
+        }
+    }
+}
+
+/// Run every instruction of the memory, and return the sum of the multiplications.
+fn run(memory: &str, conditionals: bool) -> i64 {
+    let mut interpreter = Interpreter::new(conditionals);
+    for instruction in tokenize(memory) {
+        interpreter.execute(instruction);
+    }
+    interpreter.sum()
+}
+
+fn parse_and_execute(code: &str) -> i64 {
+    run(code, false)
+}
+
+fn parse_and_execute_with_do(line: &str) -> i64 {
+    run(line, true)
+}
+
+/// Return the corrupted memory of the computer.
+pub fn parse(content: &str) -> Result<String, ParseError> {
+    Ok(content.to_string())
+}
+
+pub fn solve_part1(memory: &str) -> i64 {
+    let result = parse_and_execute(memory);
+    result
+}
+
+pub fn solve_part2(memory: &str) -> i64 {
+    let result = parse_and_execute_with_do(memory);
+    result
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
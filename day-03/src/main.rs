@@ -1,93 +1,79 @@
-use regex::Regex;
-use std::fs;
+use common::Args;
+use day_03::stream::{self, DEFAULT_CHUNK_SIZE};
+use day_03::{parse, render, solve_part1, solve_part2, trace, EXAMPLES};
+use std::process;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let fname = "data/test_input";
-        let result = solve_part1(fname);
-        assert_eq!(result, 161);
-    }
-
-    #[test]
-    fn test_part2() {
-        let fname = "data/test_input_2";
-        let result = solve_part2(fname);
-        assert_eq!(result, 48);
-    }
-
-    #[test]
-    fn test_parse_and_execute() {
-        let result = parse_and_execute("ajlfasdmul(5,4)alksjf");
-        assert_eq!(result, 20);
-    }
-
-    #[test]
-    fn test_parse_and_execute_with_do() {
-        let result =
-            parse_and_execute_with_do("ajlfasdmul(5,4)alkdon't()sjmul(3,4)lajdon't()ajdmul(3,9)f");
-        assert_eq!(result, 20);
+/// Run the memory of a part chunk by chunk, returning the sums of both parts, or exit on errors.
+fn stream_memory(args: &Args, part: u32) -> (i64, i64) {
+    let sums = args
+        .open_input(&EXAMPLES, part)
+        .and_then(|input| stream::execute(input, DEFAULT_CHUNK_SIZE));
+    match sums {
+        Ok(sums) => sums,
+        Err(e) => {
+            eprintln!("Couldn't read input: {e}");
+            process::exit(1);
+        }
     }
 }
 
-fn parse_and_execute(code: &str) -> i32 {
-    let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
-    let result = {
-        let mut result = 0;
-        for capture in re.captures_iter(code) {
-            let first: i32 = (&capture[1]).parse().expect("Couldn't parse");
-            let second: i32 = (&capture[2]).parse().expect("Couldn't parse");
-            result += first * second
+/// Read the memory that a part of the puzzle is solved on, or exit on errors.
+fn read_memory(args: &Args, part: u32) -> String {
+    let content = match args.read_input(&EXAMPLES, part) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Couldn't read input: {e}");
+            process::exit(1);
         }
-        result
     };
-    result
-}
-
-fn parse_and_execute_with_do(line: &str) -> i32 {
-    let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)|don't\(\)|do\(\)").unwrap();
-    let result = {
-        let mut result = 0;
-        let mut run = true;
-        for capture in re.captures_iter(line) {
-            if (&capture[0]).eq("do()") {
-                run = true;
-                continue;
-            }
-            if (&capture[0]).eq("don't()") {
-                run = false;
-                continue;
-            }
-            if run {
-                let first: i32 = (&capture[1]).parse().expect("Couldn't parse");
-                let second: i32 = (&capture[2]).parse().expect("Couldn't parse");
-                result += first * second
-            }
+    match parse(&content) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
         }
-        result
-    };
-    result
-}
-
-pub fn solve_part1(fname: &str) -> i32 {
-    let content = fs::read_to_string(fname).expect("Couldn't read!");
-    let result = parse_and_execute(&content);
-    result
-}
-
-pub fn solve_part2(fname: &str) -> i32 {
-    let content = fs::read_to_string(fname).expect("Couldn't read!");
-    let result = parse_and_execute_with_do(&content);
-    result
+    }
 }
 
 fn main() {
-    let fname = "data/input";
-    let result = solve_part1(fname);
-    println!("Solution to part 1: {result}");
-    let result = solve_part2(fname);
-    println!("Solution to part 2: {result}");
+    let args = Args::from_env_with(2, &["--trace", "--stream"]);
+    // Run the memory chunk by chunk, solving both parts in a single pass unless their examples
+    // differ
+    if args.flag("--stream") {
+        let (part1, part2) = stream_memory(&args, args.first_part());
+        if args.solves(1) {
+            println!("Solution to part 1: {part1}");
+        }
+        if args.solves(2) {
+            let part2 = match args.shares_input(&EXAMPLES) {
+                true => part2,
+                false => stream_memory(&args, 2).1,
+            };
+            println!("Solution to part 2: {part2}");
+        }
+        return;
+    }
+    let memory = read_memory(&args, args.first_part());
+    if args.solves(1) {
+        let result = solve_part1(&memory);
+        println!("Solution to part 1: {result}");
+        if args.flag("--trace") {
+            println!("{}", render(&memory, &trace(&memory, false)));
+        }
+    }
+    if args.solves(2) {
+        let memory = match args.shares_input(&EXAMPLES) {
+            true => memory,
+            false => read_memory(&args, 2),
+        };
+        let result = solve_part2(&memory);
+        println!("Solution to part 2: {result}");
+        if args.flag("--trace") {
+            let trace = trace(&memory, true);
+            println!("{}", render(&memory, &trace));
+            for range in trace.disabled.iter() {
+                println!("Disabled: bytes {}..{}", range.start, range.end);
+            }
+        }
+    }
 }
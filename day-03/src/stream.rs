@@ -0,0 +1,82 @@
+use std::io::{self, Read};
+
+use crate::{Interpreter, Tokenizer};
+
+/// Number of bytes read at a time by default.
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 16;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::{solve_part1, solve_part2};
+    use common::Rng;
+
+    /// Reader that returns at most a few bytes at a time, like a pipe.
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = self.step.min(buf.len()).min(self.bytes.len());
+            buf[..n].copy_from_slice(&self.bytes[..n]);
+            self.bytes = &self.bytes[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_split_instructions() {
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        // Every chunk size splits the instructions at a different place
+        for chunk_size in 1..=16 {
+            let sums = execute(memory.as_bytes(), chunk_size).unwrap();
+            assert_eq!(sums, (161, 48));
+        }
+        for step in 1..=5 {
+            let reader = Trickle {
+                bytes: memory.as_bytes(),
+                step,
+            };
+            assert_eq!(execute(reader, DEFAULT_CHUNK_SIZE).unwrap(), (161, 48));
+        }
+    }
+
+    #[test]
+    fn test_matches_in_memory() {
+        let memory = generate(&mut Rng::new(3), 20_000);
+        let expected = (solve_part1(&memory), solve_part2(&memory));
+        for chunk_size in [7, 4096, DEFAULT_CHUNK_SIZE] {
+            assert_eq!(execute(memory.as_bytes(), chunk_size).unwrap(), expected);
+        }
+    }
+}
+
+/// Run a memory read in chunks of `chunk_size` bytes, without keeping it whole in memory.
+///
+/// Returns the sums of both parts of the puzzle: without and with `do()` and `don't()`. The
+/// tokenizer keeps the start of the instructions split between two chunks, while the
+/// interpreters keep their state.
+pub fn execute(mut reader: impl Read, chunk_size: usize) -> io::Result<(i64, i64)> {
+    let mut tokenizer = Tokenizer::new();
+    let mut part1 = Interpreter::new(false);
+    let mut part2 = Interpreter::new(true);
+    let mut chunk = vec![0; chunk_size.max(1)];
+    loop {
+        let n = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        for byte in chunk[..n].iter() {
+            if let Some(token) = tokenizer.push(*byte) {
+                part1.execute(token.instruction);
+                part2.execute(token.instruction);
+            }
+        }
+    }
+    Ok((part1.sum(), part2.sum()))
+}
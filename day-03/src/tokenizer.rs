@@ -0,0 +1,203 @@
+use std::ops::Range;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let memory = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let expected = [
+            Instruction::Mul(2, 4),
+            Instruction::Dont,
+            Instruction::Mul(5, 5),
+            Instruction::Mul(11, 8),
+            Instruction::Do,
+            Instruction::Mul(8, 5),
+        ];
+        assert_eq!(tokenize(memory), expected);
+    }
+
+    #[test]
+    fn test_operands() {
+        assert_eq!(tokenize("mul(123,4)"), [Instruction::Mul(123, 4)]);
+        assert!(tokenize("mul(1234,4)mul(1,)mul(,1)mul(1 ,2)mul(-1,2)").is_empty());
+        assert!(tokenize("do(1)don't(2)mul(1,2,3)").is_empty());
+    }
+
+    #[test]
+    fn test_overlapping_candidates() {
+        // A failed candidate doesn't hide the instructions that start inside it
+        let expected = [Instruction::Mul(2, 3), Instruction::Do];
+        assert_eq!(tokenize("mul(1,mul(2,3)mumul(do()"), expected);
+        assert_eq!(tokenize("dodon'tdo()"), [Instruction::Do]);
+    }
+
+    #[test]
+    fn test_spans() {
+        let mut tokenizer = Tokenizer::new();
+        let tokens: Vec<Token> = "mul(1,mul(2,3)do()"
+            .bytes()
+            .filter_map(|byte| tokenizer.push(byte))
+            .collect();
+        let spans: Vec<Range<usize>> = tokens.into_iter().map(|token| token.span).collect();
+        assert_eq!(spans, [6..14, 14..18]);
+    }
+}
+
+/// Instructions of the corrupted memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Multiply two numbers and add the result to the sum
+    Mul(i32, i32),
+    /// Enable the `mul` instructions that follow
+    Do,
+    /// Disable the `mul` instructions that follow
+    Dont,
+}
+
+/// An instruction along with the bytes of the memory it spans.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub instruction: Instruction,
+    pub span: Range<usize>,
+}
+
+/// An instruction of the language, written as its name followed by its operands in parentheses.
+struct Opcode {
+    name: &'static str,
+    operands: usize,
+    build: fn(&[i32]) -> Instruction,
+}
+
+/// Every instruction the tokenizer recognizes.
+const OPCODES: &[Opcode] = &[
+    Opcode {
+        name: "mul",
+        operands: 2,
+        build: |x| Instruction::Mul(x[0], x[1]),
+    },
+    Opcode {
+        name: "do",
+        operands: 0,
+        build: |_| Instruction::Do,
+    },
+    Opcode {
+        name: "don't",
+        operands: 0,
+        build: |_| Instruction::Dont,
+    },
+];
+
+/// Largest number of digits of an operand.
+const MAX_DIGITS: usize = 3;
+
+/// How some bytes match an instruction.
+enum Match {
+    /// They can't be the start of an instruction
+    Invalid,
+    /// They're the start of an instruction, but it isn't complete yet
+    Prefix,
+    Complete(Instruction),
+}
+
+impl Opcode {
+    fn matches(&self, bytes: &[u8]) -> Match {
+        let name = self.name.as_bytes();
+        let n = bytes.len().min(name.len());
+        if bytes[..n] != name[..n] {
+            return Match::Invalid;
+        }
+        let Some(rest) = bytes[n..].strip_prefix(b"(") else {
+            return match bytes.len() == n {
+                true => Match::Prefix,
+                false => Match::Invalid,
+            };
+        };
+        let mut rest = rest;
+        let mut operands = vec![];
+        for i in 0..self.operands {
+            let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+            if digits > MAX_DIGITS {
+                return Match::Invalid;
+            }
+            if digits == rest.len() {
+                return Match::Prefix;
+            }
+            if digits == 0 {
+                return Match::Invalid;
+            }
+            let number = std::str::from_utf8(&rest[..digits]).unwrap();
+            operands.push(number.parse().unwrap());
+            let separator = match i + 1 < self.operands {
+                true => b',',
+                false => b')',
+            };
+            if rest[digits] != separator {
+                return Match::Invalid;
+            }
+            rest = &rest[digits + 1..];
+        }
+        if self.operands == 0 {
+            match rest.first() {
+                None => return Match::Prefix,
+                Some(b')') => (),
+                Some(_) => return Match::Invalid,
+            }
+        }
+        Match::Complete((self.build)(&operands))
+    }
+}
+
+/// Tokenizer of the corrupted memory, which gets it one byte at a time.
+///
+/// It keeps the bytes of the leftmost candidate instruction. When a new byte rules it out, it
+/// drops its first byte and looks for a new candidate in the rest of them.
+#[derive(Debug, Default)]
+pub struct Tokenizer {
+    candidate: Vec<u8>,
+    /// Number of bytes pushed so far
+    offset: usize,
+}
+
+impl Tokenizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the next byte of the memory, returning the instruction that it completes, if any.
+    pub fn push(&mut self, byte: u8) -> Option<Token> {
+        self.candidate.push(byte);
+        self.offset += 1;
+        while !self.candidate.is_empty() {
+            let mut prefix = false;
+            for opcode in OPCODES.iter() {
+                match opcode.matches(&self.candidate) {
+                    Match::Complete(instruction) => {
+                        let span = self.offset - self.candidate.len()..self.offset;
+                        self.candidate.clear();
+                        return Some(Token { instruction, span });
+                    }
+                    Match::Prefix => prefix = true,
+                    Match::Invalid => (),
+                }
+            }
+            if prefix {
+                return None;
+            }
+            self.candidate.remove(0);
+        }
+        None
+    }
+}
+
+/// Return every instruction of the memory, in order.
+pub fn tokenize(memory: &str) -> Vec<Instruction> {
+    let mut tokenizer = Tokenizer::new();
+    let instructions = memory
+        .bytes()
+        .filter_map(|byte| tokenizer.push(byte))
+        .map(|token| token.instruction)
+        .collect();
+    instructions
+}
@@ -0,0 +1,155 @@
+use std::ops::Range;
+
+use crate::{Instruction, Interpreter, Tokenizer};
+
+/// Terminal styles of the rendering: bold green, red crossed out, cyan and dim.
+const EXECUTED: &str = "\x1b[1;32m";
+const SKIPPED: &str = "\x1b[9;31m";
+const CONDITIONAL: &str = "\x1b[36m";
+const DISABLED: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "mul(2,4)don't()_mul(5,5)undo()?mul(8,5)don't()";
+
+    #[test]
+    fn test_trace() {
+        let trace = trace(EXAMPLE, true);
+        assert_eq!(trace.sum, 48);
+        let steps: Vec<(Range<usize>, bool)> = trace
+            .steps
+            .iter()
+            .filter(|step| matches!(step.instruction, Instruction::Mul(_, _)))
+            .map(|step| (step.span.clone(), step.executed))
+            .collect();
+        assert_eq!(steps, [(0..8, true), (16..24, false), (31..39, true)]);
+        assert_eq!(trace.disabled, [15..26, 46..46]);
+    }
+
+    #[test]
+    fn test_trace_without_conditionals() {
+        let trace = trace(EXAMPLE, false);
+        assert_eq!(trace.sum, 73);
+        assert!(trace
+            .steps
+            .iter()
+            .all(|step| step.executed == step.is_mul()));
+        assert!(trace.disabled.is_empty());
+    }
+
+    #[test]
+    fn test_render() {
+        let memory = "xmul(2,4)don't()?mul(1,1)do()";
+        let rendered = render(memory, &trace(memory, true));
+        let expected = format!(
+            "x{EXECUTED}mul(2,4){RESET}{CONDITIONAL}don't(){RESET}{DISABLED}?{RESET}\
+             {SKIPPED}mul(1,1){RESET}{CONDITIONAL}do(){RESET}"
+        );
+        assert_eq!(rendered, expected);
+    }
+}
+
+/// An instruction recognized in the memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub instruction: Instruction,
+    /// Bytes of the memory that the instruction spans
+    pub span: Range<usize>,
+    /// Whether the instruction had any effect
+    pub executed: bool,
+}
+
+impl Step {
+    fn is_mul(&self) -> bool {
+        matches!(self.instruction, Instruction::Mul(_, _))
+    }
+}
+
+/// Every instruction recognized while running a memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+    /// Ranges of bytes between a `don't()` and the next `do()`, or the end of the memory
+    pub disabled: Vec<Range<usize>>,
+    pub sum: i64,
+}
+
+/// Run the memory, keeping track of every instruction and of the disabled parts.
+///
+/// Without conditionals, `do()` and `don't()` are recognized but skipped.
+pub fn trace(memory: &str, conditionals: bool) -> Trace {
+    let mut tokenizer = Tokenizer::new();
+    let mut interpreter = Interpreter::new(conditionals);
+    let mut steps = vec![];
+    let mut disabled = vec![];
+    let mut disabled_since = None;
+    for token in memory.bytes().filter_map(|byte| tokenizer.push(byte)) {
+        let executed = match token.instruction {
+            Instruction::Mul(_, _) => interpreter.is_enabled(),
+            Instruction::Do | Instruction::Dont => conditionals,
+        };
+        let was_enabled = interpreter.is_enabled();
+        interpreter.execute(token.instruction);
+        match (was_enabled, interpreter.is_enabled(), disabled_since) {
+            (true, false, _) => disabled_since = Some(token.span.end),
+            (false, true, Some(start)) => {
+                disabled.push(start..token.span.start);
+                disabled_since = None;
+            }
+            _ => (),
+        }
+        steps.push(Step {
+            instruction: token.instruction,
+            span: token.span,
+            executed,
+        });
+    }
+    if let Some(start) = disabled_since {
+        disabled.push(start..memory.len());
+    }
+    Trace {
+        steps,
+        disabled,
+        sum: interpreter.sum(),
+    }
+}
+
+/// Add the text between two instructions, dimmed if it's in a disabled range.
+fn push_text(output: &mut String, memory: &str, text: Range<usize>, disabled: &[Range<usize>]) {
+    if text.is_empty() {
+        return;
+    }
+    match disabled.iter().any(|range| range.contains(&text.start)) {
+        true => output.extend([DISABLED, &memory[text], RESET]),
+        false => output.push_str(&memory[text]),
+    }
+}
+
+/// Render the memory for a terminal, highlighting the instructions that were executed or skipped.
+///
+/// Executed multiplications are bold green and skipped ones are red and crossed out, while
+/// `do()` and `don't()` are cyan and the rest of the disabled memory is dimmed.
+pub fn render(memory: &str, trace: &Trace) -> String {
+    let mut output = String::new();
+    let mut position = 0;
+    for step in trace.steps.iter() {
+        push_text(
+            &mut output,
+            memory,
+            position..step.span.start,
+            &trace.disabled,
+        );
+        let style = match (step.is_mul(), step.executed) {
+            (true, true) => EXECUTED,
+            (true, false) => SKIPPED,
+            (false, _) => CONDITIONAL,
+        };
+        output.extend([style, &memory[step.span.clone()], RESET]);
+        position = step.span.end;
+    }
+    push_text(&mut output, memory, position..memory.len(), &trace.disabled);
+    output
+}
@@ -0,0 +1,27 @@
+use common::Rng;
+use grid::Grid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 40);
+        let soup = parse(&input).unwrap();
+        assert_eq!((soup.width(), soup.height()), (40, 40));
+        assert!(solve_part1(&soup) > 0);
+        assert!(solve_part2(&soup) > 0);
+    }
+}
+
+/// Generate a square word search of `size` by `size` letters.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let letters = ['X', 'M', 'A', 'S'];
+    let mut soup = Grid::new(size, size, 'X');
+    for point in soup.points().collect::<Vec<_>>() {
+        soup[point] = *rng.choose(&letters);
+    }
+    soup.to_string()
+}
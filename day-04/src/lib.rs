@@ -0,0 +1,90 @@
+use common::{Answer, ParseError, Solution};
+use grid::Grid;
+
+mod first;
+pub mod generate;
+mod second;
+mod words;
+
+pub use first::solve_part1;
+pub use second::solve_part2;
+pub use words::{find_words, WordMatch};
+
+const DAY: u32 = 4;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn test_part1() {
+        let soup = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&soup);
+        assert_eq!(result, 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let soup = parse(EXAMPLE).unwrap();
+        let result = solve_part2(&soup);
+        assert_eq!(result, 9);
+    }
+
+    #[test]
+    fn test_rectangular_soup() {
+        let soup = parse("XMASAMX\nMMXSAMX\n").unwrap();
+        assert_eq!((soup.width(), soup.height()), (7, 2));
+        assert_eq!(solve_part1(&soup), 3);
+        assert!(parse("XMAS\nXM4S\n").is_err());
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day04>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["18", "9"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Parse a rectangular grid of letters.
+pub fn parse(content: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_with(DAY, content, |c| c.is_alphabetic().then_some(c))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
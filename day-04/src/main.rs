@@ -1,29 +1,39 @@
-mod first;
-mod second;
+use common::Args;
+use day_04::{find_words, parse, solve_part1, solve_part2, EXAMPLES};
+use std::process;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let fname = "data/test_input";
-        let result = first::solve_part1(fname);
-        assert_eq!(result, 18);
+fn main() {
+    let args = Args::from_env_with(2, &["--words LIST"]);
+    let content = match args.read_input(&EXAMPLES, args.first_part()) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Couldn't read input: {e}");
+            process::exit(1);
+        }
+    };
+    let soup = match parse(&content) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+    // Search for the given comma-separated words instead of solving the puzzle
+    if let Some(list) = args.option("--words") {
+        let words: Vec<&str> = list.split(",").filter(|w| !w.is_empty()).collect();
+        let matches = find_words(&soup, &words);
+        for m in matches.iter() {
+            println!("{} at {:?} going {:?}", m.word, m.start, m.direction);
+        }
+        println!("Words found: {}", matches.len());
+        return;
     }
-
-    #[test]
-    fn test_part2() {
-        let fname = "data/test_input";
-        let result = second::solve_part2(fname);
-        assert_eq!(result, 9);
+    if args.solves(1) {
+        let result = solve_part1(&soup);
+        println!("Solution to part 1: {result}");
+    }
+    if args.solves(2) {
+        let result = solve_part2(&soup);
+        println!("Solution to part 2: {result}");
     }
-}
-
-fn main() {
-    let fname = "data/input";
-    let result = first::solve_part1(&fname);
-    println!("Solution to part 1: {result}");
-    let result = second::solve_part2(&fname);
-    println!("Solution to part 2: {result}");
 }
@@ -1,8 +1,8 @@
-use std::fs;
+use grid::{Grid, Point};
 
-fn is_cross(soup: &Vec<Vec<char>>, i: usize, j: usize) -> bool {
-    let (bottom_left, top_right) = (soup[i - 1][j - 1], soup[i + 1][j + 1]);
-    let (bottom_right, top_left) = (soup[i + 1][j - 1], soup[i - 1][j + 1]);
+fn is_cross(soup: &Grid<char>, (x, y): Point) -> bool {
+    let (bottom_left, top_right) = (soup[(x - 1, y - 1)], soup[(x + 1, y + 1)]);
+    let (bottom_right, top_left) = (soup[(x - 1, y + 1)], soup[(x + 1, y - 1)]);
     if do_form_sam(bottom_left, top_right) && do_form_sam(bottom_right, top_left) {
         return true;
     };
@@ -16,16 +16,13 @@ fn do_form_sam(a: char, b: char) -> bool {
     return false;
 }
 
-pub fn solve_part2(fname: &str) -> i32 {
-    let content = fs::read_to_string(&fname).expect("Couldn't read");
-    let soup: Vec<Vec<char>> = content.lines().map(|line| line.chars().collect()).collect();
-    let n = soup.len();
+pub fn solve_part2(soup: &Grid<char>) -> i32 {
     let mut counts = 0;
     // Avoid searching for 'A' on the edges: they cannot be the center of any cross
-    for i in 1..n - 1 {
-        for j in 1..n - 1 {
-            if soup[i][j] == 'A' {
-                counts += is_cross(&soup, i, j) as i32;
+    for y in 1..soup.height().saturating_sub(1) {
+        for x in 1..soup.width().saturating_sub(1) {
+            if soup[(x, y)] == 'A' {
+                counts += is_cross(soup, (x, y)) as i32;
             };
         }
     }
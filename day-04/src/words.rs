@@ -0,0 +1,108 @@
+use grid::{Direction, Grid, Point};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const SOUP: &str = "\
+CATS
+OXAT
+WTAC
+";
+
+    #[test]
+    fn test_find_words() {
+        let soup = parse(SOUP).unwrap();
+        let matches = find_words(&soup, &["CAT", "COW", "DOG"]);
+        let expected = [
+            WordMatch {
+                word: "CAT",
+                start: (0, 0),
+                direction: Direction::East,
+            },
+            WordMatch {
+                word: "COW",
+                start: (0, 0),
+                direction: Direction::South,
+            },
+            WordMatch {
+                word: "CAT",
+                start: (3, 2),
+                direction: Direction::West,
+            },
+        ];
+        assert_eq!(matches, expected);
+    }
+
+    #[test]
+    fn test_words_longer_than_grid() {
+        let soup = parse(SOUP).unwrap();
+        assert!(find_words(&soup, &["CATSS", "WTACO", ""]).is_empty());
+        // Palindromes are found once in each direction they can be read
+        let soup = parse("ABA\nXYZ\n").unwrap();
+        assert_eq!(find_words(&soup, &["ABA"]).len(), 2);
+    }
+
+    #[test]
+    fn test_single_letter_words() {
+        let soup = parse(SOUP).unwrap();
+        let matches = find_words(&soup, &["O", "S"]);
+        let starts: Vec<Point> = matches.iter().map(|m| m.start).collect();
+        assert_eq!(starts, [(3, 0), (0, 1)]);
+        assert!(matches.iter().all(|m| m.direction == Direction::ALL[0]));
+    }
+}
+
+/// A word found in a grid of letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordMatch<'a> {
+    pub word: &'a str,
+    /// Position of the first letter of the word
+    pub start: Point,
+    /// Direction the word is read in from its first letter
+    pub direction: Direction,
+}
+
+/// Return true if the word can be read from the start in the given direction.
+fn reads(soup: &Grid<char>, word: &str, start: Point, direction: Direction) -> bool {
+    let mut point = Some(start);
+    for letter in word.chars() {
+        match point {
+            Some(p) if soup[p] == letter => point = soup.next(p, direction),
+            _ => return false,
+        }
+    }
+    return true;
+}
+
+/// Find every occurrence of the words in horizontal, vertical and diagonal directions.
+///
+/// The matches are sorted by their start in row-major order, and then in the order of the words
+/// and of `Direction::ALL`. Words that read the same in two directions, like palindromes, are
+/// found once in each. Single letters read the same in every direction, so they're only found
+/// once, going in the first of them.
+pub fn find_words<'a>(soup: &Grid<char>, words: &[&'a str]) -> Vec<WordMatch<'a>> {
+    let mut matches = vec![];
+    for (start, letter) in soup.iter() {
+        for word in words.iter() {
+            if !word.starts_with(*letter) {
+                continue;
+            }
+            let directions = match word.chars().count() {
+                1 => &Direction::ALL[..1],
+                _ => &Direction::ALL[..],
+            };
+            for direction in directions.iter().copied() {
+                if reads(soup, word, start, direction) {
+                    matches.push(WordMatch {
+                        word,
+                        start,
+                        direction,
+                    });
+                }
+            }
+        }
+    }
+    matches
+}
@@ -0,0 +1,56 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 50);
+        let (rules, updates) = parse(&input).unwrap();
+        assert_eq!(updates.len(), 50);
+        assert!(solve_part1(&rules, &updates) > 0);
+        assert!(solve_part2(&rules, &updates) > 0);
+    }
+}
+
+const N_PAGES: usize = 49;
+
+/// Generate a puzzle input with `size` updates.
+///
+/// The rules define a total order over 49 pages, so every pair of pages in an update can be
+/// compared. About half of the updates are already in the right order.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<i64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(N_PAGES);
+
+    let mut rules = vec![];
+    for i in 0..N_PAGES {
+        for j in i + 1..N_PAGES {
+            rules.push((pages[i], pages[j]));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let mut input = String::new();
+    for (lower, greater) in rules {
+        input.push_str(&format!("{lower}|{greater}\n"));
+    }
+    input.push('\n');
+
+    for _ in 0..size {
+        // Updates need a middle page, so they have an odd number of them
+        let length = 2 * rng.range(2, 12) as usize + 1;
+        let mut indices: Vec<usize> = (0..N_PAGES).collect();
+        rng.shuffle(&mut indices);
+        indices.truncate(length);
+        if rng.chance(0.5) {
+            indices.sort();
+        }
+        let update: Vec<String> = indices.iter().map(|i| pages[*i].to_string()).collect();
+        input.push_str(&update.join(","));
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,243 @@
+use common::{lines, Answer, ParseError, Solution};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+pub mod generate;
+
+const DAY: u32 = 5;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_part1() {
+        let (rules, updates) = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&rules, &updates);
+        assert_eq!(result, 143);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (rules, updates) = parse(EXAMPLE).unwrap();
+        let result = solve_part2(&rules, &updates);
+        assert_eq!(result, 123);
+    }
+
+    #[test]
+    fn test_quicksort() {
+        let dict: HashMap<i32, Vec<i32>> = HashMap::from([(1, vec![2, 3]), (2, vec![3])]);
+        let rules = Rules { dict };
+        let sorted = vec![1, 2, 3];
+
+        let mut update = vec![1, 2, 3];
+        quicksort(&mut update[..], &rules);
+        assert_eq!(update, sorted);
+
+        let mut update = vec![3, 2, 1];
+        quicksort(&mut update[..], &rules);
+        assert_eq!(update, sorted);
+
+        let mut update = vec![2, 3, 1];
+        quicksort(&mut update[..], &rules);
+        assert_eq!(update, sorted);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day05>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["143", "123"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Debug)]
+pub struct Rules {
+    dict: HashMap<i32, Vec<i32>>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rules {
+    pub fn new() -> Self {
+        let dict: HashMap<i32, Vec<i32>> = HashMap::new();
+        Self { dict }
+    }
+
+    pub fn add_rule(&mut self, lower: i32, greater: i32) {
+        self.dict
+            .entry(lower)
+            .and_modify(|v| v.push(greater))
+            .or_insert(vec![greater]);
+    }
+
+    pub fn is_lower(&self, a: i32, b: i32) -> bool {
+        match self.dict.get(&a) {
+            Some(lower_values) => return lower_values.contains(&b),
+            None => match self.dict.get(&b) {
+                Some(lower_values) => return !lower_values.contains(&a),
+                None => panic!(
+                    "Couldn't find a rule to determine ordering of {} and {}",
+                    a, b
+                ),
+            },
+        }
+    }
+}
+
+fn check_update_ordered(update: &[i32], rules: &Rules) -> bool {
+    for values in update.windows(2) {
+        let (left, right) = (values[0], values[1]);
+        if let Ordering::Greater = compare(left, right, rules) {
+            return false;
+        }
+    }
+    true
+}
+
+fn compare(left: i32, right: i32, rules: &Rules) -> Ordering {
+    match rules.is_lower(left, right) {
+        true => Ordering::Less,
+        false => Ordering::Greater,
+    }
+}
+
+fn quicksort(update: &mut [i32], rules: &Rules) {
+    if update.len() < 2 {
+        return;
+    }
+    let pivot_index = {
+        let n = update.len();
+        let pivot = update[n - 1];
+        let mut pivot_index: usize = 0;
+        for i in 0..n - 1 {
+            if let Ordering::Less = compare(update[i], pivot, rules) {
+                update.swap(i, pivot_index);
+                pivot_index += 1;
+            }
+        }
+        update.swap(pivot_index, n - 1);
+        pivot_index
+    };
+
+    quicksort(&mut update[..pivot_index], rules);
+    quicksort(&mut update[pivot_index + 1..], rules);
+}
+
+pub fn parse(content: &str) -> Result<(Rules, Vec<Vec<i32>>), ParseError> {
+    let mut lines = lines(DAY, content);
+
+    let rules = {
+        let mut rules = Rules::new();
+        for line in lines.by_ref() {
+            if line.text.is_empty() {
+                break;
+            };
+            let mut values = line.text.split("|");
+            let left: i32 = line.parse(values.next().unwrap())?;
+            let right: i32 = match values.next() {
+                Some(value) => line.parse(value)?,
+                None => return Err(line.missing("'|' separator")),
+            };
+            rules.add_rule(left, right);
+        }
+        rules
+    };
+
+    let mut updates = vec![];
+    for line in lines {
+        let update = line
+            .text
+            .split(",")
+            .map(|x| line.parse(x))
+            .collect::<Result<Vec<i32>, ParseError>>()?;
+        updates.push(update);
+    }
+    Ok((rules, updates))
+}
+
+pub fn solve_part1(rules: &Rules, updates: &[Vec<i32>]) -> i32 {
+    let mut result = 0;
+    for update in updates.iter() {
+        if check_update_ordered(update, rules) {
+            result += update[update.len() / 2];
+        };
+    }
+
+    result
+}
+
+pub fn solve_part2(rules: &Rules, updates: &[Vec<i32>]) -> i32 {
+    let mut result = 0;
+    for update in updates.iter() {
+        if !check_update_ordered(update, rules) {
+            let mut update = update.clone();
+            quicksort(&mut update[..], rules);
+            result += update[update.len() / 2];
+        };
+    }
+
+    result
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Rules, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (rules, updates) = input;
+        solve_part1(rules, updates).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let (rules, updates) = input;
+        solve_part2(rules, updates).into()
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use grid::frames::{Frames, Rgb};
+use grid::Grid;
+
+use crate::{parse, Guard, Location, Map};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::frames::Palette;
+    use std::{env, fs, process};
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_animate() {
+        let dir = env::temp_dir().join(format!("day-06-frames-{}", process::id()));
+        let mut frames = Frames::new(&dir, Palette::new(PALETTE)).unwrap();
+        animate(EXAMPLE, &mut frames).unwrap();
+        // One frame for the start and one for each of the 54 steps or turns of the guard
+        assert_eq!(frames.written(), 55);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_animate_loop() {
+        let dir = env::temp_dir().join(format!("day-06-loop-frames-{}", process::id()));
+        let mut frames = Frames::new(&dir, Palette::new(PALETTE)).unwrap();
+        let error = animate(".#..\n...#\n#...\n.^#.\n", &mut frames).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the guard walks in a loop and never leaves the map"
+        );
+        // The start, and the steps and turns until the guard is back to a previous state
+        assert_eq!(frames.written(), 10);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Colors of the empty and visited positions, the obstacles and the guard.
+pub const PALETTE: &[(char, Rgb)] = &[
+    ('.', [24, 24, 40]),
+    ('X', [70, 110, 190]),
+    ('#', [150, 150, 150]),
+    ('@', [250, 200, 40]),
+];
+
+fn snapshot(map: &Map, guard: &Guard) -> Grid<char> {
+    let mut tiles = map.map.map(|location| match location {
+        Location::Empty => '.',
+        Location::Obstacle => '#',
+        Location::Visited => 'X',
+    });
+    tiles[guard.position] = '@';
+    tiles
+}
+
+/// Record the patrol of the guard, one frame per step or turn, until it leaves the map.
+///
+/// If the guard walks in a loop, stop once it gets back to a previous position and orientation,
+/// and return an error after writing the frames.
+pub fn animate(content: &str, frames: &mut Frames) -> Result<(), Box<dyn Error>> {
+    let (mut map, mut guard) = parse(content)?;
+    let mut states = HashSet::new();
+    let mut loops = false;
+    frames.record(&snapshot(&map, &guard))?;
+    while !guard.is_outside(&map) {
+        if !guard.record_state(&mut states) {
+            loops = true;
+            break;
+        }
+        if !guard.step_forward(&mut map) {
+            guard.rotate()
+        };
+        frames.record(&snapshot(&map, &guard))?;
+    }
+    frames.finish(&snapshot(&map, &guard))?;
+    if loops {
+        return Err("the guard walks in a loop and never leaves the map".into());
+    }
+    Ok(())
+}
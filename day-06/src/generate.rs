@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use common::Rng;
+use grid::{Direction, Grid};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 30);
+        let (map, guard) = parse(&input).unwrap();
+        assert!(solve_part1(&map, &guard).is_some_and(|visited| visited > 0));
+    }
+}
+
+/// Check that a guard starting in `start` and facing north leaves the map without looping.
+fn leaves_map(map: &Grid<char>, start: (usize, usize)) -> bool {
+    let mut seen = HashSet::new();
+    let (mut position, mut orientation) = (start, Direction::North);
+    while seen.insert((position, orientation)) {
+        match map.next(position, orientation) {
+            None => return true,
+            Some(next) if map[next] == '#' => orientation = orientation.turn_right(),
+            Some(next) => position = next,
+        }
+    }
+    false
+}
+
+/// Generate a square map of `size` by `size` tiles.
+///
+/// The guard always manages to leave the map: maps where it gets stuck in a loop are discarded.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let mut map = Grid::new(size, size, '.');
+        for point in map.points().collect::<Vec<_>>() {
+            if rng.chance(0.02) {
+                map[point] = '#';
+            }
+        }
+        let x = rng.below(size as u64) as usize;
+        let y = rng.below(size as u64) as usize;
+        map[(x, y)] = '.';
+        if leaves_map(&map, (x, y)) {
+            map[(x, y)] = '^';
+            return map.to_string();
+        }
+    }
+}
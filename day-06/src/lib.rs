@@ -0,0 +1,176 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+
+pub mod animate;
+pub mod generate;
+
+const DAY: u32 = 6;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 1] = ["data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_part1() {
+        let (map, guard) = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&map, &guard);
+        assert_eq!(result, Some(41));
+    }
+
+    #[test]
+    fn test_part1_loop() {
+        let (map, guard) = parse(".#..\n...#\n#...\n.^#.\n").unwrap();
+        assert_eq!(solve_part1(&map, &guard), None);
+        // Surrounded by obstacles, the guard turns forever
+        let (map, guard) = parse(".#.\n#^#\n.#.\n").unwrap();
+        assert_eq!(solve_part1(&map, &guard), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_character() {
+        let error = parse("..#.\n.^x.\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (6, 2, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day06>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["41"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Location {
+    Obstacle,
+    Empty,
+    Visited,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    map: Grid<Location>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Guard {
+    position: Point,
+    orientation: Direction,
+}
+
+impl Guard {
+    fn next_position(&self, map: &Map) -> Option<Point> {
+        return map.map.next(self.position, self.orientation);
+    }
+
+    fn is_outside(&self, map: &Map) -> bool {
+        return self.next_position(map).is_none();
+    }
+
+    /// Record the position and orientation of the guard, and return false if they were already
+    /// recorded, which means that the guard walks in a loop.
+    fn record_state(&self, states: &mut HashSet<(Point, Direction)>) -> bool {
+        return states.insert((self.position, self.orientation));
+    }
+
+    fn rotate(&mut self) {
+        self.orientation = self.orientation.turn_right();
+    }
+
+    fn step_forward(&mut self, map: &mut Map) -> bool {
+        let Some(next_position) = self.next_position(map) else {
+            return false;
+        };
+        if let Location::Obstacle = map.map[next_position] {
+            return false;
+        } else {
+            map.map[next_position] = Location::Visited;
+            self.position = next_position;
+            return true;
+        };
+    }
+}
+
+pub fn parse(content: &str) -> Result<(Map, Guard), ParseError> {
+    let tiles = Grid::parse_with(DAY, content, |c| ".#^><v".contains(c).then_some(c))?;
+    let guard = tiles.iter().find_map(|(position, c)| {
+        let orientation = Direction::from_char(*c)?;
+        Some(Guard {
+            position,
+            orientation,
+        })
+    });
+    let Some(guard) = guard else {
+        return Err(ParseError::new(DAY, 1, 1, "", "couldn't find the guard"));
+    };
+    let map = tiles.map(|c| match c {
+        '.' => Location::Empty,
+        '#' => Location::Obstacle,
+        _ => Location::Visited,
+    });
+    Ok((Map { map }, guard))
+}
+
+/// Count the positions visited by the guard before leaving the map, or return None if the guard
+/// walks in a loop and never leaves it.
+pub fn solve_part1(map: &Map, guard: &Guard) -> Option<i32> {
+    let (mut map, mut guard) = (map.clone(), guard.clone());
+    let mut states = HashSet::new();
+    loop {
+        if guard.is_outside(&map) {
+            break;
+        }
+        if !guard.record_state(&mut states) {
+            return None;
+        }
+        if !guard.step_forward(&mut map) {
+            guard.rotate()
+        };
+    }
+    let visited = map
+        .map
+        .iter()
+        .filter(|(_, location)| matches!(location, Location::Visited))
+        .count();
+    Some(visited as i32)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Map, Guard);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (map, guard) = input;
+        match solve_part1(map, guard) {
+            Some(visited) => visited.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 100);
+        let equations = parse(&input).unwrap();
+        assert_eq!(equations.len(), 100);
+        let (first, second) = (solve_part1(&equations), solve_part2(&equations));
+        assert!(0 < first && first < second);
+    }
+}
+
+/// Largest test value, so the solver can't overflow while trying other operators.
+const MAX_RESULT: i64 = 1_000_000_000_000_000;
+
+/// Combine the numbers with random operators, or return `None` if the result gets too large.
+fn random_result(rng: &mut Rng, numbers: &[i64]) -> Option<i64> {
+    let mut result = numbers[0];
+    for number in numbers[1..].iter() {
+        result = match rng.below(3) {
+            0 => result.checked_add(*number)?,
+            1 => result.checked_mul(*number)?,
+            _ => {
+                let digits = number.to_string().len() as u32;
+                result
+                    .checked_mul(10_i64.pow(digits))?
+                    .checked_add(*number)?
+            }
+        };
+    }
+    (result < MAX_RESULT).then_some(result)
+}
+
+/// Generate a puzzle input with `size` equations.
+///
+/// The test values come from applying random operators to the numbers, and some of them are
+/// shifted so the equation (most likely) can't be solved.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut n_equations = 0;
+    while n_equations < size {
+        let length = rng.range(2, 13) as usize;
+        let numbers: Vec<i64> = (0..length).map(|_| rng.range(1, 1000)).collect();
+        let Some(mut result) = random_result(rng, &numbers) else {
+            continue;
+        };
+        if rng.chance(0.3) {
+            result += rng.range(1, 100);
+        }
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        input.push_str(&format!("{result}: {}\n", numbers.join(" ")));
+        n_equations += 1;
+    }
+    input
+}
@@ -0,0 +1,170 @@
+use common::{lines, parallel, Answer, ParseError, Solution};
+use itertools::Itertools;
+use std::iter;
+
+pub mod generate;
+
+const DAY: u32 = 7;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn test_part1() {
+        let equations = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&equations);
+        assert_eq!(result, 3749);
+    }
+    #[test]
+    fn test_part2() {
+        let equations = parse(EXAMPLE).unwrap();
+        let result = solve_part2(&equations);
+        assert_eq!(result, 11387);
+    }
+
+    #[test]
+    fn test_parallel() {
+        let equations = parse(&generate::generate(&mut common::Rng::new(1), 200)).unwrap();
+        let expected = (solve_part1(&equations), solve_part2(&equations));
+        parallel::set_workers(4);
+        let result = (solve_part1(&equations), solve_part2(&equations));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day07>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["3749", "11387"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Debug)]
+enum Operator {
+    Sum,
+    Product,
+    Concat,
+}
+
+impl Operator {
+    fn operate(&self, x: i64, y: i64) -> i64 {
+        match self {
+            Operator::Sum => x + y,
+            Operator::Product => x * y,
+            Operator::Concat => concat(x, y),
+        }
+    }
+}
+
+fn concat(a: i64, b: i64) -> i64 {
+    a * 10i64.pow(b.ilog10() + 1) + b
+}
+
+fn is_equation_valid(expected_result: i64, factors: &[i64], operator_types: &[Operator]) -> bool {
+    let combinations =
+        itertools::repeat_n(operator_types.iter(), factors.len() - 1).multi_cartesian_product();
+    for operators in combinations {
+        // Apply operators
+        let result = {
+            let mut result = factors[0];
+            for (operator, value) in iter::zip(operators, &factors[1..]) {
+                result = operator.operate(result, *value);
+                // Stop the iterations if the result is already larger than the expected one
+                if result > expected_result {
+                    break;
+                }
+            }
+            result
+        };
+
+        if result == expected_result {
+            return true;
+        }
+    }
+    return false;
+}
+
+pub type Equation = (i64, Vec<i64>);
+
+pub fn parse(content: &str) -> Result<Vec<Equation>, ParseError> {
+    let mut equations = vec![];
+    for line in lines(DAY, content) {
+        let mut equation = line.text.split(":");
+        let expected_result: i64 = line.parse(equation.next().unwrap())?;
+        let factors = match equation.next() {
+            Some(factors) => factors
+                .split_whitespace()
+                .map(|x| line.parse(x))
+                .collect::<Result<Vec<i64>, ParseError>>()?,
+            None => return Err(line.missing("':' separator")),
+        };
+        if factors.is_empty() {
+            return Err(line.missing("numbers"));
+        }
+        equations.push((expected_result, factors));
+    }
+    Ok(equations)
+}
+
+fn sum_valid_equations(equations: &[Equation], operator_types: &[Operator]) -> i64 {
+    let results = parallel::map(
+        equations,
+        |(expected_result, factors)| match is_equation_valid(
+            *expected_result,
+            factors,
+            operator_types,
+        ) {
+            true => *expected_result,
+            false => 0,
+        },
+    );
+    results.iter().sum()
+}
+
+pub fn solve_part1(equations: &[Equation]) -> i64 {
+    let operator_types = vec![Operator::Sum, Operator::Product];
+    sum_valid_equations(equations, &operator_types)
+}
+
+pub fn solve_part2(equations: &[Equation]) -> i64 {
+    let operator_types = vec![Operator::Sum, Operator::Product, Operator::Concat];
+    sum_valid_equations(equations, &operator_types)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part1(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part2(input).into()
+    }
+}
@@ -0,0 +1,40 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part1, solve_part2};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 50);
+        let (antennas, ncols) = parse(&input).unwrap();
+        assert_eq!(ncols, 50);
+        let (first, second) = (solve_part1(&antennas, ncols), solve_part2(&antennas, ncols));
+        assert!(0 < first && first < second);
+    }
+}
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generate a square map of `size` by `size` tiles.
+///
+/// About one in every twelve tiles holds an antenna, with frequencies shared by four antennas on
+/// average.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut map = vec![vec!['.'; size]; size];
+    let n_antennas = size * size / 12;
+    let n_frequencies = (n_antennas / 4).clamp(1, FREQUENCIES.len());
+    let frequencies = &FREQUENCIES[..n_frequencies];
+    for _ in 0..n_antennas {
+        let row = rng.below(size as u64) as usize;
+        let col = rng.below(size as u64) as usize;
+        map[row][col] = *rng.choose(frequencies) as char;
+    }
+    let mut input = String::new();
+    for row in map {
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
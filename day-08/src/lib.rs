@@ -0,0 +1,213 @@
+use common::{lines, Answer, ParseError, Solution};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub mod generate;
+
+const DAY: u32 = 8;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn test_part_1() {
+        let (antennas, ncols) = parse(EXAMPLE).unwrap();
+        let result = solve_part1(&antennas, ncols);
+        assert_eq!(result, 14);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (antennas, ncols) = parse(EXAMPLE).unwrap();
+        let result = solve_part2(&antennas, ncols);
+        assert_eq!(result, 34);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day08>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["14", "34"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    fn is_inside(&self, nrows: i32, ncols: i32) -> bool {
+        if (self.x < 0) | (self.y < 0) {
+            return false;
+        };
+        if (self.x >= ncols) | (self.y >= nrows) {
+            return false;
+        };
+        return true;
+    }
+}
+
+fn get_antinodes(antenna_a: &Position, antenna_b: &Position) -> (Position, Position) {
+    let x_diff = antenna_b.x - antenna_a.x;
+    let y_diff = antenna_b.y - antenna_a.y;
+    let antinode_1 = Position {
+        x: antenna_a.x - x_diff,
+        y: antenna_a.y - y_diff,
+    };
+    let antinode_2 = Position {
+        x: antenna_b.x + x_diff,
+        y: antenna_b.y + y_diff,
+    };
+    return (antinode_1, antinode_2);
+}
+
+fn get_all_antinodes(
+    antenna_a: &Position,
+    antenna_b: &Position,
+    nrows: i32,
+    ncols: i32,
+) -> Vec<Position> {
+    // Return positions of all antinodes, including the resonant harmonics
+    let x_diff = antenna_b.x - antenna_a.x;
+    let y_diff = antenna_b.y - antenna_a.y;
+
+    let mut antinodes = vec![];
+    let mut i = 0;
+    loop {
+        let antinode = Position {
+            x: antenna_a.x - i * x_diff,
+            y: antenna_a.y - i * y_diff,
+        };
+        if !antinode.is_inside(nrows, ncols) {
+            break;
+        }
+        antinodes.push(antinode);
+        i += 1;
+    }
+    let mut i = 0;
+    loop {
+        let antinode = Position {
+            x: antenna_b.x + i * x_diff,
+            y: antenna_b.y + i * y_diff,
+        };
+        if !antinode.is_inside(nrows, ncols) {
+            break;
+        }
+        antinodes.push(antinode);
+        i += 1;
+    }
+    return antinodes;
+}
+
+pub type Antennas = HashMap<char, Vec<Position>>;
+
+pub fn parse(content: &str) -> Result<(Antennas, i32), ParseError> {
+    let ncols = match content.lines().next() {
+        Some(line) => line.len() as i32,
+        None => return Err(ParseError::new(DAY, 1, 1, "", "empty map")),
+    };
+    let mut antennas: HashMap<char, Vec<Position>> = HashMap::new();
+    for (row, line) in lines(DAY, content).enumerate() {
+        if line.text.len() as i32 != ncols {
+            return Err(line.error(line.text, "rows have different lengths"));
+        }
+        for (col, character) in line.text.chars().enumerate() {
+            match character {
+                '.' => (),
+                c if !c.is_ascii_alphanumeric() => {
+                    return Err(line.error_at(col, "invalid antenna frequency"))
+                }
+                _ => {
+                    antennas
+                        .entry(character)
+                        .and_modify(|p| {
+                            p.push(Position {
+                                x: row as i32,
+                                y: col as i32,
+                            })
+                        })
+                        .or_insert(vec![Position {
+                            x: row as i32,
+                            y: col as i32,
+                        }]);
+                }
+            }
+        }
+    }
+    Ok((antennas, ncols))
+}
+
+pub fn solve_part1(antennas: &Antennas, ncols: i32) -> i32 {
+    let nrows = ncols; // assume a square
+
+    let mut antinodes: Vec<Position> = vec![];
+    for (_, antenna_locations) in antennas.iter() {
+        for pair in antenna_locations.iter().combinations(2) {
+            let (antinode_1, antinode_2) = get_antinodes(pair[0], pair[1]);
+            if antinode_1.is_inside(nrows, ncols) {
+                antinodes.push(antinode_1);
+            }
+            if antinode_2.is_inside(nrows, ncols) {
+                antinodes.push(antinode_2);
+            }
+        }
+    }
+    antinodes.iter().unique().count() as i32
+}
+
+pub fn solve_part2(antennas: &Antennas, ncols: i32) -> i32 {
+    let nrows = ncols; // assume a square
+
+    let mut antinodes: Vec<Position> = vec![];
+    for (_, antenna_locations) in antennas.iter() {
+        for pair in antenna_locations.iter().combinations(2) {
+            antinodes.extend(get_all_antinodes(pair[0], pair[1], nrows, ncols))
+        }
+    }
+    antinodes.iter().unique().count() as i32
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = (Antennas, i32);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (antennas, ncols) = input;
+        solve_part1(antennas, *ncols).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let (antennas, ncols) = input;
+        solve_part2(antennas, *ncols).into()
+    }
+}
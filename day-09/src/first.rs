@@ -1,6 +1,5 @@
 use std::cmp::Ordering;
 use std::fmt;
-use std::fs;
 
 #[derive(Copy, Clone, Debug)]
 enum Block {
@@ -67,7 +66,7 @@ fn move_file_block(file_block: Block, disk_map: &mut Vec<Block>) -> bool {
     return true;
 }
 
-fn get_index_next_free_space(disk_map: &Vec<Block>) -> Option<usize> {
+fn get_index_next_free_space(disk_map: &[Block]) -> Option<usize> {
     for (i, block) in disk_map.iter().enumerate() {
         if let Block::FreeSpace { size: _ } = block {
             return Some(i);
@@ -76,13 +75,7 @@ fn get_index_next_free_space(disk_map: &Vec<Block>) -> Option<usize> {
     return None;
 }
 
-fn read_disk_map(fname: &str) -> Vec<Block> {
-    let content = fs::read_to_string(fname).expect("Couldn't read");
-    let digits: Vec<u32> = content
-        .chars()
-        .filter(|c| c.is_numeric())
-        .map(|c| c.to_digit(10).unwrap())
-        .collect();
+fn new_disk_map(digits: &[u32]) -> Vec<Block> {
     let mut disk_map = vec![];
     for (i, digit) in digits.iter().enumerate() {
         if i % 2 == 0 {
@@ -105,13 +98,16 @@ fn defrag(disk_map: &mut Vec<Block>) {
             Block::File { id: _, size: _ } => move_file_block(last_file, disk_map),
         };
         if !result {
-            return ();
+            return;
         }
     }
 }
 
-pub fn solve_part_one(fname: &str) -> u64 {
-    let mut disk_map = read_disk_map(fname);
+pub fn solve_part_one(digits: &[u32]) -> u64 {
+    checksum_after_defrag(new_disk_map(digits))
+}
+
+fn checksum_after_defrag(mut disk_map: Vec<Block>) -> u64 {
     defrag(&mut disk_map);
 
     let mut result = 0;
@@ -0,0 +1,32 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 501);
+        let disk_map = parse(&input).unwrap();
+        assert_eq!(disk_map.len(), 501);
+        assert!(solve_part_one(&disk_map) > 0);
+        assert!(solve_part_two(&disk_map) > 0);
+    }
+}
+
+/// Generate a disk map of `size` digits.
+///
+/// Files take between one and nine blocks, and the free spaces between them up to nine.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for i in 0..size {
+        let digit = match i % 2 {
+            0 => rng.range(1, 10),
+            _ => rng.range(0, 10),
+        };
+        input.push_str(&digit.to_string());
+    }
+    input.push('\n');
+    input
+}
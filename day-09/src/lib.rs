@@ -0,0 +1,91 @@
+use common::{lines, Answer, ParseError, Solution};
+
+mod first;
+pub mod generate;
+pub mod oracle;
+mod second;
+
+pub use first::solve_part_one;
+pub use second::solve_part_two;
+
+const DAY: u32 = 9;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn test_part_one() {
+        let digits = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&digits);
+        assert_eq!(result, 1928);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let digits = parse(EXAMPLE).unwrap();
+        let result = solve_part_two(&digits);
+        assert_eq!(result, 2858);
+    }
+
+    #[test]
+    fn test_parse_empty_input() {
+        for content in ["", "\n", "\n\n"] {
+            let error = parse(content).unwrap_err();
+            assert_eq!((error.line, error.column), (1, 1));
+            assert_eq!(error.message, "missing disk map");
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day09>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["1928", "2858"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day09>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<u32>, ParseError> {
+    let mut digits = vec![];
+    for line in lines(DAY, content) {
+        for (i, c) in line.text.trim_end().chars().enumerate() {
+            match c.to_digit(10) {
+                Some(digit) => digits.push(digit),
+                None => return Err(line.error_at(i, "invalid digit")),
+            }
+        }
+    }
+    if digits.is_empty() {
+        return Err(ParseError::new(DAY, 1, 1, "", "missing disk map"));
+    }
+    Ok(digits)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
@@ -1,29 +1,29 @@
-mod first;
-mod second;
+use common::Args;
+use day_09::{parse, solve_part_one, solve_part_two, EXAMPLES};
+use std::process;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let fname = "data/test_input";
-        let result = first::solve_part_one(fname);
-        assert_eq!(result, 1928);
+fn main() {
+    let args = Args::from_env(2);
+    let content = match args.read_input(&EXAMPLES, args.first_part()) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Couldn't read input: {e}");
+            process::exit(1);
+        }
+    };
+    let digits = match parse(&content) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+    if args.solves(1) {
+        let result = solve_part_one(&digits);
+        println!("Solution to part one: {result}");
     }
-
-    #[test]
-    fn test_part_two() {
-        let fname = "data/test_input";
-        let result = second::solve_part_two(fname);
-        assert_eq!(result, 2858);
+    if args.solves(2) {
+        let result = solve_part_two(&digits);
+        println!("Solution to part two: {result}");
     }
 }
-
-fn main() {
-    let fname = "data/input";
-    let result = first::solve_part_one(fname);
-    println!("Solution to part one: {result}");
-    let result = second::solve_part_two(fname);
-    println!("Solution to part two: {result}");
-}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::{parse, solve_part_one, solve_part_two};
+    use common::{check_differential, without_each};
+
+    const EXAMPLE: &str = "2333133121414131402";
+
+    /// Shrink a disk map by removing one of its files (along with the free space after it), or by
+    /// making one of its files or free spaces smaller.
+    fn shrink(input: &str) -> Vec<String> {
+        let digits: Vec<char> = input.trim().chars().collect();
+        let pairs: Vec<&[char]> = digits.chunks(2).collect();
+        let mut candidates: Vec<String> = without_each(&pairs)
+            .iter()
+            .map(|pairs| pairs.concat().iter().collect())
+            .collect();
+        for (i, digit) in digits.iter().enumerate() {
+            if *digit > '0' {
+                let mut smaller = digits.clone();
+                smaller[i] = (*digit as u8 - 1) as char;
+                candidates.push(smaller.iter().collect());
+            }
+        }
+        candidates
+    }
+
+    #[test]
+    fn test_oracle_example() {
+        let digits = parse(EXAMPLE).unwrap();
+        assert_eq!(compact_blocks(&digits), 1928);
+        assert_eq!(compact_files(&digits), 2858);
+    }
+
+    #[test]
+    fn test_part_one_against_oracle() {
+        check_differential(
+            300,
+            30,
+            generate,
+            shrink,
+            |input| solve_part_one(&parse(input).unwrap()),
+            |input| compact_blocks(&parse(input).unwrap()),
+        );
+    }
+
+    #[test]
+    fn test_part_two_against_oracle() {
+        check_differential(
+            300,
+            30,
+            generate,
+            shrink,
+            |input| solve_part_two(&parse(input).unwrap()),
+            |input| compact_files(&parse(input).unwrap()),
+        );
+    }
+}
+
+/// Expand the disk map into blocks, holding the id of their file or `None` if they're free.
+fn expand(digits: &[u32]) -> Vec<Option<u64>> {
+    let mut blocks = vec![];
+    for (i, digit) in digits.iter().enumerate() {
+        let id = match i % 2 {
+            0 => Some(i as u64 / 2),
+            _ => None,
+        };
+        blocks.extend(std::iter::repeat_n(id, *digit as usize));
+    }
+    blocks
+}
+
+fn checksum(blocks: &[Option<u64>]) -> u64 {
+    let checksum = blocks
+        .iter()
+        .enumerate()
+        .map(|(position, id)| position as u64 * id.unwrap_or(0))
+        .sum();
+    checksum
+}
+
+/// Move the last file block to the leftmost free block, one at a time.
+pub fn compact_blocks(digits: &[u32]) -> u64 {
+    let mut blocks = expand(digits);
+    loop {
+        let free = blocks.iter().position(|b| b.is_none());
+        let last = blocks.iter().rposition(|b| b.is_some());
+        match (free, last) {
+            (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+            _ => break,
+        }
+    }
+    checksum(&blocks)
+}
+
+/// Move each whole file, in decreasing order of ids, to the leftmost span of free blocks that can
+/// hold it, if there's one to the left of the file.
+pub fn compact_files(digits: &[u32]) -> u64 {
+    let mut blocks = expand(digits);
+    let n_files = digits.len().div_ceil(2) as u64;
+    for id in (0..n_files).rev() {
+        let Some(start) = blocks.iter().position(|b| *b == Some(id)) else {
+            continue;
+        };
+        let length = blocks.iter().filter(|b| **b == Some(id)).count();
+        let free = (0..start).find(|i| blocks[*i..*i + length].iter().all(|b| b.is_none()));
+        if let Some(free) = free {
+            for i in 0..length {
+                blocks.swap(free + i, start + i);
+            }
+        }
+    }
+    checksum(&blocks)
+}
@@ -1,6 +1,5 @@
 use std::cmp::Ordering;
 use std::fmt;
-use std::fs;
 
 #[derive(Copy, Clone, Debug)]
 enum Blocks {
@@ -117,7 +116,7 @@ impl Disk {
     }
 }
 
-fn move_file<'a>(block_index: usize, disk: &mut Disk) -> bool {
+fn move_file(block_index: usize, disk: &mut Disk) -> bool {
     if let Blocks::Free(_) = disk.disk[block_index] {
         return false; // if block is a free space, we cannot move it, so return false
     };
@@ -157,13 +156,7 @@ fn defrag(disk: &mut Disk) {
     }
 }
 
-fn read_disk_map(fname: &str) -> Disk {
-    let content = fs::read_to_string(fname).expect("Couldn't read");
-    let digits: Vec<u32> = content
-        .chars()
-        .filter(|c| c.is_numeric())
-        .map(|c| c.to_digit(10).unwrap())
-        .collect();
+fn new_disk(digits: &[u32]) -> Disk {
     let mut disk = vec![];
     for (i, digit) in digits.iter().enumerate() {
         if i % 2 == 0 {
@@ -175,8 +168,8 @@ fn read_disk_map(fname: &str) -> Disk {
     return Disk { disk };
 }
 
-pub fn solve_part_two(fname: &str) -> u64 {
-    let mut disk = read_disk_map(fname);
+pub fn solve_part_two(digits: &[u32]) -> u64 {
+    let mut disk = new_disk(digits);
     defrag(&mut disk);
     return disk.checksum();
 }
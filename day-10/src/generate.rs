@@ -0,0 +1,40 @@
+use common::Rng;
+use grid::Grid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 40);
+        let topo = parse(&input).unwrap();
+        let (first, second) = (solve_part_one(&topo), solve_part_two(&topo));
+        assert!(0 < first && first <= second);
+    }
+}
+
+/// Generate a square topographic map of `size` by `size` positions (at least two by two).
+///
+/// Random heights alone would hardly make any hiking trail, so a few of them are drawn on top as
+/// random walks going from height 0 to 9.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    let mut map = Grid::new(size, size, 0);
+    for point in map.points().collect::<Vec<_>>() {
+        map[point] = rng.below(10);
+    }
+    for _ in 0..size * size / 20 {
+        let mut point = (
+            rng.below(size as u64) as usize,
+            rng.below(size as u64) as usize,
+        );
+        for height in 0..10 {
+            map[point] = height;
+            let neighbors: Vec<_> = map.neighbors4(point).collect();
+            point = *rng.choose(&neighbors);
+        }
+    }
+    map.to_string()
+}
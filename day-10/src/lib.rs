@@ -0,0 +1,145 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Point};
+
+pub mod generate;
+
+const DAY: u32 = 10;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn test_part_one() {
+        let topo = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&topo);
+        assert_eq!(result, 36);
+    }
+    #[test]
+    fn test_part_two() {
+        let topo = parse(EXAMPLE).unwrap();
+        let result = solve_part_two(&topo);
+        assert_eq!(result, 81);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day10>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["36", "81"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day10>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+pub struct Topo {
+    map: Grid<u32>,
+}
+
+impl Topo {
+    pub fn get(&self, position: &Point) -> &u32 {
+        return &self.map[*position];
+    }
+
+    pub fn get_trailheads(&self) -> Vec<Point> {
+        let trailheads: Vec<Point> = self
+            .map
+            .iter()
+            .filter(|(_, value)| **value == 0)
+            .map(|(position, _)| position)
+            .collect();
+        trailheads
+    }
+
+    pub fn get_trailhead_score(&self, position: &Point, summits: &mut Vec<Point>) -> u32 {
+        // count how many summits can be reached from this trailhead
+        if *self.get(position) == 9 && !summits.contains(position) {
+            summits.push(*position);
+            return 1;
+        }
+        let neighbors = self.get_trail_neighbours(position);
+        let result = neighbors
+            .iter()
+            .map(|n| self.get_trailhead_score(n, summits))
+            .sum();
+        return result;
+    }
+
+    pub fn get_trailhead_rating(&self, position: &Point) -> u32 {
+        // count how many trails can be followed from this trailhead
+        if *self.get(position) == 9 {
+            return 1;
+        }
+        let neighbors = self.get_trail_neighbours(position);
+        let result = neighbors.iter().map(|n| self.get_trailhead_rating(n)).sum();
+        return result;
+    }
+
+    fn get_trail_neighbours(&self, position: &Point) -> Vec<Point> {
+        let height = self.get(position);
+        let neighbors = self
+            .map
+            .neighbors4(*position)
+            .filter(|n| *self.get(n) == height + 1)
+            .collect();
+        return neighbors;
+    }
+}
+
+pub fn parse(content: &str) -> Result<Topo, ParseError> {
+    let map = Grid::parse_with(DAY, content, |c| c.to_digit(10))?;
+    Ok(Topo { map })
+}
+
+pub fn solve_part_one(topo: &Topo) -> u32 {
+    let trailheads = topo.get_trailheads();
+    trailheads
+        .iter()
+        .map(|t| {
+            let mut summits: Vec<Point> = vec![];
+            topo.get_trailhead_score(t, &mut summits)
+        })
+        .sum()
+}
+
+pub fn solve_part_two(topo: &Topo) -> u32 {
+    let trailheads = topo.get_trailheads();
+    trailheads
+        .iter()
+        .map(|t| topo.get_trailhead_rating(t))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Topo;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
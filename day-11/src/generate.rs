@@ -0,0 +1,28 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 8);
+        let stones = parse(&input).unwrap();
+        assert_eq!(stones.len(), 8);
+        assert!(solve_part_one(&stones) < solve_part_two(&stones));
+    }
+}
+
+/// Generate an arrangement of `size` stones.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size)
+        .map(|_| match rng.chance(0.2) {
+            true => rng.range(0, 10).to_string(),
+            false => rng.range(0, 10_000_000).to_string(),
+        })
+        .collect();
+    let mut input = stones.join(" ");
+    input.push('\n');
+    input
+}
@@ -0,0 +1,165 @@
+use common::{lines, Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+pub mod generate;
+
+const DAY: u32 = 11;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+125 17
+";
+
+    #[test]
+    fn test_part_one() {
+        let stones = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&stones);
+        assert_eq!(result, 55312);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day11>(
+            env!("CARGO_MANIFEST_DIR"),
+            &EXAMPLES,
+            &["55312", "65601038650482"],
+        );
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+enum Stone {
+    Single(u64),
+    Pair(u64, u64),
+}
+
+struct Counts {
+    counts: HashMap<u64, u64>,
+}
+
+impl Counts {
+    fn new(stones: Vec<u64>) -> Self {
+        let mut counts: HashMap<u64, u64> = HashMap::new();
+        for stone in stones {
+            counts.entry(stone).and_modify(|c| *c += 1).or_insert(1);
+        }
+        return Self { counts };
+    }
+
+    fn blink(&mut self) {
+        let mut counts: HashMap<u64, u64> = HashMap::new();
+        for (stone, quantity) in self.counts.iter() {
+            match blink_stone(*stone) {
+                Stone::Single(new_stone) => {
+                    counts
+                        .entry(new_stone)
+                        .and_modify(|c| *c += quantity)
+                        .or_insert(*quantity);
+                }
+                Stone::Pair(new_stone_1, new_stone_2) => {
+                    counts
+                        .entry(new_stone_1)
+                        .and_modify(|c| *c += quantity)
+                        .or_insert(*quantity);
+                    counts
+                        .entry(new_stone_2)
+                        .and_modify(|c| *c += quantity)
+                        .or_insert(*quantity);
+                }
+            }
+        }
+        self.counts = counts;
+    }
+
+    fn count_stones(&self) -> u64 {
+        self.counts.values().sum()
+    }
+}
+
+fn count_digits(integer: u64) -> u64 {
+    if integer == 0 {
+        return 1;
+    };
+    let mut n_digits = 1;
+    let mut tmp = integer;
+    loop {
+        tmp /= 10;
+        if tmp == 0 {
+            break;
+        }
+        n_digits += 1;
+    }
+    return n_digits;
+}
+
+fn blink_stone(stone: u64) -> Stone {
+    // Blink a single stone
+    if stone == 0 {
+        return Stone::Single(1);
+    };
+    let n_digits = count_digits(stone);
+    if n_digits.is_multiple_of(2) {
+        let left = stone / u64::pow(10, n_digits as u32 / 2);
+        let right = stone - left * u64::pow(10, n_digits as u32 / 2);
+        return Stone::Pair(left, right);
+    } else {
+        return Stone::Single(stone * 2024);
+    };
+}
+
+pub fn parse(content: &str) -> Result<Vec<u64>, ParseError> {
+    let mut stones = vec![];
+    for line in lines(DAY, content) {
+        for number in line.text.split_whitespace() {
+            stones.push(line.parse(number)?);
+        }
+    }
+    Ok(stones)
+}
+
+fn count_stones_after_blinks(stones: &[u64], n_blinks: u32) -> u64 {
+    let mut counts = Counts::new(stones.to_vec());
+    for _ in 0..n_blinks {
+        counts.blink()
+    }
+    counts.count_stones()
+}
+
+pub fn solve_part_one(stones: &[u64]) -> u64 {
+    let n_blinks = 25;
+    count_stones_after_blinks(stones, n_blinks)
+}
+
+pub fn solve_part_two(stones: &[u64]) -> u64 {
+    let n_blinks = 75;
+    count_stones_after_blinks(stones, n_blinks)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
@@ -0,0 +1,45 @@
+use common::Rng;
+use grid::Grid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 40, 25);
+        let garden = parse(&input).unwrap();
+        assert_eq!((garden.plants.width(), garden.plants.height()), (40, 25));
+        let (first, second) = (solve_part_one(&garden), solve_part_two(&garden));
+        assert!(0 < second && second < first);
+    }
+}
+
+/// Generate a garden of `width` by `height` plots.
+///
+/// Plots are grouped in regions around random seeds, each one growing a random plant, with some
+/// noise so the regions have irregular borders.
+pub fn generate(rng: &mut Rng, width: usize, height: usize) -> String {
+    let n_seeds = (width * height / 40).max(1);
+    let seeds: Vec<(i64, i64, char)> = (0..n_seeds)
+        .map(|_| {
+            let x = rng.below(width as u64) as i64;
+            let y = rng.below(height as u64) as i64;
+            let plant = (b'A' + rng.below(26) as u8) as char;
+            (x, y, plant)
+        })
+        .collect();
+    let mut garden = Grid::new(width, height, 'A');
+    for (x, y) in garden.points().collect::<Vec<_>>() {
+        // Pick the closest seed, measuring the distance with some noise
+        let (_, _, plant) = seeds
+            .iter()
+            .min_by_key(|(sx, sy, _)| {
+                (sx - x as i64).abs() + (sy - y as i64).abs() + rng.range(0, 3)
+            })
+            .unwrap();
+        garden[(x, y)] = *plant;
+    }
+    garden.to_string()
+}
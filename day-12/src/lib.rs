@@ -0,0 +1,362 @@
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Grid};
+
+pub mod generate;
+pub mod oracle;
+
+const DAY: u32 = 12;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input_3", "data/test_input_3"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE_1: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const EXAMPLE_2: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    const EXAMPLE_3: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const EXAMPLE_4: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const EXAMPLE_5: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn test_part_one_1() {
+        let garden = parse(EXAMPLE_1).unwrap();
+        let result = solve_part_one(&garden);
+        assert_eq!(result, 140);
+    }
+
+    #[test]
+    fn test_part_one_2() {
+        let garden = parse(EXAMPLE_2).unwrap();
+        let result = solve_part_one(&garden);
+        assert_eq!(result, 772);
+    }
+
+    #[test]
+    fn test_part_one_3() {
+        let garden = parse(EXAMPLE_3).unwrap();
+        let result = solve_part_one(&garden);
+        assert_eq!(result, 1930);
+    }
+
+    #[test]
+    fn test_part_two_1() {
+        let garden = parse(EXAMPLE_1).unwrap();
+        let result = solve_part_two(&garden);
+        assert_eq!(result, 80);
+    }
+
+    #[test]
+    fn test_part_two_2() {
+        let garden = parse(EXAMPLE_2).unwrap();
+        let result = solve_part_two(&garden);
+        assert_eq!(result, 436);
+    }
+
+    #[test]
+    fn test_part_two_3() {
+        let garden = parse(EXAMPLE_3).unwrap();
+        let result = solve_part_two(&garden);
+        assert_eq!(result, 1206);
+    }
+
+    #[test]
+    fn test_part_two_4() {
+        let garden = parse(EXAMPLE_4).unwrap();
+        let result = solve_part_two(&garden);
+        assert_eq!(result, 236);
+    }
+
+    #[test]
+    fn test_part_two_5() {
+        let garden = parse(EXAMPLE_5).unwrap();
+        let result = solve_part_two(&garden);
+        assert_eq!(result, 368);
+    }
+
+    #[test]
+    fn test_count_corners() {
+        let garden = parse(EXAMPLE_1).unwrap();
+        let corners = |x, y| garden.get_plot(x, y).count_corners(&garden);
+        // Plots along a side have no corners, while a region of a single plot has four
+        assert_eq!(corners(1, 0), 0);
+        assert_eq!(corners(3, 1), 4);
+        // The region of C has a concave corner on each side of its bend, next to D and E
+        assert_eq!(corners(2, 2), 2);
+        assert_eq!(corners(3, 2), 2);
+        let region = [(2, 1), (2, 2), (3, 2), (3, 3)];
+        let sides: u32 = region.iter().map(|(x, y)| corners(*x, *y)).sum();
+        assert_eq!(sides, 8);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day12>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["1930", "1206"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Plot {
+    x: i32,
+    y: i32,
+    plant: char,
+}
+
+impl Plot {
+    fn was_visited(&self, visited: &BoolMap) -> bool {
+        return visited.map[(self.x as usize, self.y as usize)];
+    }
+
+    fn get_neighbors(&self, garden: &Garden) -> Vec<Plot> {
+        // Return a vec neighbors of the same plant type.
+        let position = (self.x as usize, self.y as usize);
+        let mut neighbors = vec![];
+        for (x, y) in garden.plants.neighbors4(position) {
+            let neighbor = garden.get_plot(x as i32, y as i32);
+            if neighbor.plant == self.plant {
+                neighbors.push(neighbor);
+            }
+        }
+        neighbors
+    }
+
+    fn count_corners(&self, garden: &Garden) -> u32 {
+        // Count how many corners of the region are located on this plot
+        //
+        // A region has as many sides as corners, so counting the corners of every plot gives us
+        // the number of sides of the region. For each one of the diagonal directions, the plot has
+        // a convex corner if neither of the two adjacent plots belong to the region, and a concave
+        // corner if both of them do but the diagonal one doesn't.
+        let mut n_corners = 0;
+        for direction in Direction::ORDINAL {
+            let (dx, dy) = direction.delta();
+            let (dx, dy) = (dx as i32, dy as i32);
+            let horizontal = garden.is_same_plant(self, self.x + dx, self.y);
+            let vertical = garden.is_same_plant(self, self.x, self.y + dy);
+            let diagonal = garden.is_same_plant(self, self.x + dx, self.y + dy);
+            if (!horizontal && !vertical) | (horizontal && vertical && !diagonal) {
+                n_corners += 1;
+            }
+        }
+        n_corners
+    }
+}
+
+struct BoolMap {
+    map: Grid<bool>,
+}
+
+impl BoolMap {
+    fn new(garden: &Garden) -> Self {
+        let map = Grid::new(garden.plants.width(), garden.plants.height(), false);
+        Self { map }
+    }
+
+    fn visit(&mut self, plot: &Plot) {
+        self.map[(plot.x as usize, plot.y as usize)] = true;
+    }
+
+    fn update(&mut self, other: BoolMap) {
+        for (position, visited) in other.map.iter() {
+            if *visited {
+                self.map[position] = true
+            }
+        }
+    }
+}
+
+pub struct Garden {
+    plants: Grid<char>,
+}
+
+impl Garden {
+    pub fn new(plants: Grid<char>) -> Self {
+        Self { plants }
+    }
+
+    fn get_area_and_perimeter(&self, plot: Plot, visited: &mut BoolMap) -> (u32, u32) {
+        // Compute area and perimeter of a region of plots of same type
+        let (mut area, mut perimeter) = (0, 0);
+        let mut stack: Vec<Plot> = vec![plot];
+        while let Some(plot) = stack.pop() {
+            // Pop last element in stack
+
+            // If we already visited this neighbor, just continue
+            if plot.was_visited(visited) {
+                continue;
+            };
+            // Get neighbors of same type of plant
+            let neighbors = plot.get_neighbors(self);
+            // Increase area on one, and perimeter as (4 - number of neighbors)
+            area += 1;
+            perimeter += 4 - neighbors.len() as u32;
+            // Mark this plot as visited
+            visited.visit(&plot);
+            // Add unvisited neighbors to the stack
+            let unvisited_neighbors: Vec<Plot> = neighbors
+                .iter()
+                .filter(|n| !n.was_visited(visited))
+                .cloned()
+                .collect();
+            stack.extend(unvisited_neighbors);
+        }
+        (area, perimeter)
+    }
+
+    fn get_area_and_sides(&self, plot: Plot, visited: &mut BoolMap) -> (u32, u32) {
+        // Compute area and number of sides of a region of plots of same type
+        let (mut area, mut sides) = (0, 0);
+        // Create a stack to store the plots that we need to visit
+        let mut stack: Vec<Plot> = vec![plot];
+        // Create a temporary visited struct just to mark the visited plots of this particular
+        // region.
+        let mut visited_tmp = BoolMap::new(self);
+        while let Some(plot) = stack.pop() {
+            // Pop last element in stack
+
+            // If we already visited this neighbor, just continue
+            if plot.was_visited(&visited_tmp) {
+                continue;
+            };
+            // Get neighbors of same type of plant
+            let neighbors = plot.get_neighbors(self);
+            // Increase area on one, and perimeter as (4 - number of neighbors)
+            area += 1;
+            // Count sides as the number of corners of the region
+            sides += plot.count_corners(self);
+            // Mark this plot as visited
+            visited_tmp.visit(&plot);
+            // Add unvisited neighbors to the stack
+            let unvisited_neighbors: Vec<Plot> = neighbors
+                .iter()
+                .filter(|n| !n.was_visited(&visited_tmp))
+                .cloned()
+                .collect();
+            stack.extend(unvisited_neighbors);
+        }
+        visited.update(visited_tmp);
+        (area, sides)
+    }
+
+    fn get_plant(&self, x: i32, y: i32) -> char {
+        return self.plants[(x as usize, y as usize)];
+    }
+
+    fn is_same_plant(&self, plot: &Plot, x: i32, y: i32) -> bool {
+        // Check if the plot in (x, y) has the same plant as the given one. Plots outside the
+        // garden are never of the same plant.
+        if (x < 0) | (y < 0) {
+            return false;
+        }
+        self.plants.get(x as usize, y as usize) == Some(&plot.plant)
+    }
+
+    fn get_plot(&self, x: i32, y: i32) -> Plot {
+        Plot {
+            x,
+            y,
+            plant: self.get_plant(x, y),
+        }
+    }
+}
+
+pub fn parse(content: &str) -> Result<Garden, ParseError> {
+    let plants = Grid::parse_with(DAY, content, |c| c.is_ascii_uppercase().then_some(c))?;
+    return Ok(Garden::new(plants));
+}
+
+pub fn solve_part_one(garden: &Garden) -> u32 {
+    // Returns price of all regions in the garden
+    let mut visited = BoolMap::new(garden);
+    let mut price = 0;
+    // use y for rows (vertical axis) and x for cols (horizontal)
+    for (x, y) in garden.plants.points() {
+        let plot = garden.get_plot(x as i32, y as i32);
+        if plot.was_visited(&visited) {
+            continue;
+        };
+        let (area, perimeter) = garden.get_area_and_perimeter(plot, &mut visited);
+        price += area * perimeter;
+    }
+    price
+}
+
+pub fn solve_part_two(garden: &Garden) -> u32 {
+    // Returns price of all regions in the garden (counting sides instead perimeter)
+    let mut visited = BoolMap::new(garden);
+    let mut price = 0;
+    // use y for rows (vertical axis) and x for cols (horizontal)
+    for (x, y) in garden.plants.points() {
+        let plot = garden.get_plot(x as i32, y as i32);
+        if plot.was_visited(&visited) {
+            continue;
+        };
+        let (area, sides) = garden.get_area_and_sides(plot, &mut visited);
+        price += area * sides;
+    }
+    price
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
@@ -0,0 +1,153 @@
+use grid::{Direction, Grid, Point};
+
+use crate::Garden;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::{parse, solve_part_one, solve_part_two};
+    use common::{check_differential, without_each, Rng};
+
+    const EXAMPLE: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+    /// Shrink a garden by removing one of its rows or columns.
+    fn shrink(input: &str) -> Vec<String> {
+        let rows: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+        let mut candidates: Vec<Vec<Vec<char>>> = without_each(&rows);
+        for column in 0..rows[0].len() {
+            let removed = rows
+                .iter()
+                .map(|row| without_each(row).swap_remove(column))
+                .collect();
+            candidates.push(removed);
+        }
+        let candidates = candidates
+            .iter()
+            .filter(|rows| !rows.is_empty() && !rows[0].is_empty())
+            .map(|rows| {
+                let lines: Vec<String> = rows.iter().map(|row| row.iter().collect()).collect();
+                lines.join("\n")
+            })
+            .collect();
+        candidates
+    }
+
+    #[test]
+    fn test_oracle_example() {
+        let garden = parse(EXAMPLE).unwrap();
+        assert_eq!(fence_price(&garden, false), 772);
+        assert_eq!(fence_price(&garden, true), 436);
+    }
+
+    #[test]
+    fn test_part_one_against_oracle() {
+        check_differential(
+            200,
+            12,
+            |rng, size| generate(rng, size, size),
+            shrink,
+            |input| solve_part_one(&parse(input).unwrap()),
+            |input| fence_price(&parse(input).unwrap(), false),
+        );
+    }
+
+    #[test]
+    fn test_part_two_against_oracle() {
+        check_differential(
+            200,
+            12,
+            |rng, size| generate(rng, size, size),
+            shrink,
+            |input| solve_part_two(&parse(input).unwrap()),
+            |input| fence_price(&parse(input).unwrap(), true),
+        );
+    }
+
+    #[test]
+    fn test_non_square_against_oracle() {
+        // Gardens with a random height, from a single row to twice their width
+        let generate = |rng: &mut Rng, size: usize| {
+            let height = rng.range(1, 2 * size as i64 + 1) as usize;
+            generate(rng, size, height)
+        };
+        check_differential(
+            200,
+            12,
+            generate,
+            shrink,
+            |input| {
+                let garden = parse(input).unwrap();
+                (solve_part_one(&garden), solve_part_two(&garden))
+            },
+            |input| {
+                let garden = parse(input).unwrap();
+                (fence_price(&garden, false), fence_price(&garden, true))
+            },
+        );
+    }
+}
+
+/// Find the plots of the region that contains `start` with a flood fill.
+fn region(plants: &Grid<char>, start: Point) -> Vec<Point> {
+    let mut region = vec![start];
+    let mut i = 0;
+    while i < region.len() {
+        for neighbor in plants.neighbors4(region[i]) {
+            if plants[neighbor] == plants[start] && !region.contains(&neighbor) {
+                region.push(neighbor);
+            }
+        }
+        i += 1;
+    }
+    region
+}
+
+/// Check if there's a fence on one side of a plot, i.e. if the plot on that side is missing or
+/// grows a different plant.
+fn has_fence(plants: &Grid<char>, plot: Point, direction: Direction) -> bool {
+    match plants.next(plot, direction) {
+        Some(neighbor) => plants[neighbor] != plants[plot],
+        None => true,
+    }
+}
+
+/// Compute the total price of the fences, counting either the whole perimeter or the sides.
+///
+/// A piece of fence starts a new side unless the plot to its left (looking from inside the
+/// region) is in the same region and has a fence on the same side.
+pub fn fence_price(garden: &Garden, sides: bool) -> u32 {
+    let plants = &garden.plants;
+    let mut visited: Vec<Point> = vec![];
+    let mut price = 0;
+    for start in plants.points() {
+        if visited.contains(&start) {
+            continue;
+        }
+        let region = region(plants, start);
+        let mut fences = 0;
+        for plot in region.iter() {
+            for direction in Direction::CARDINAL {
+                if !has_fence(plants, *plot, direction) {
+                    continue;
+                }
+                let continues_side = match plants.next(*plot, direction.turn_left()) {
+                    Some(left) => region.contains(&left) && has_fence(plants, left, direction),
+                    None => false,
+                };
+                if !sides || !continues_side {
+                    fences += 1;
+                }
+            }
+        }
+        price += region.len() as u32 * fences;
+        visited.extend(region);
+    }
+    price
+}
@@ -0,0 +1,59 @@
+use common::Rng;
+
+use crate::OFFSET;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 100);
+        let machines = parse(&input).unwrap();
+        assert_eq!(machines.len(), 100);
+        assert!(solve_part_one(&machines) > 0);
+        assert!(solve_part_two(&machines) > 0);
+    }
+}
+
+/// Generate a puzzle input with `size` claw machines.
+///
+/// A fifth of the machines have buttons that move the claw in the same direction. A third of the
+/// prizes can be won with at most a hundred presses of each button, another third can be won once
+/// the prizes get moved by the offset of the second part, and the rest of them are placed at
+/// random.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut machines = vec![];
+    while machines.len() < size {
+        let (mut ax, mut ay) = (rng.range(10, 100), rng.range(10, 100));
+        let (mut bx, mut by) = (rng.range(10, 100), rng.range(10, 100));
+        if rng.chance(0.2) {
+            // Both buttons move the claw by multiples of the same step
+            let (dx, dy) = (rng.range(3, 20), rng.range(3, 20));
+            let (a, b) = (rng.range(1, 6), rng.range(1, 6));
+            (ax, ay, bx, by) = (a * dx, a * dy, b * dx, b * dy);
+        }
+        let det = ax * by - bx * ay;
+        let (px, py) = match rng.below(3) {
+            0 => {
+                let (a, b) = (rng.range(0, 101), rng.range(0, 101));
+                (a * ax + b * bx, a * ay + b * by)
+            }
+            1 if det != 0 => {
+                // Find the (real) number of presses that reach the offset, and add a few more
+                let a = OFFSET * (by - bx) / det + rng.range(1, 100);
+                let b = OFFSET * (ax - ay) / det + rng.range(1, 100);
+                (a * ax + b * bx - OFFSET, a * ay + b * by - OFFSET)
+            }
+            _ => (rng.range(1000, 20000), rng.range(1000, 20000)),
+        };
+        if px < 0 || py < 0 {
+            continue;
+        }
+        machines.push(format!(
+            "Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}\n"
+        ));
+    }
+    machines.join("\n")
+}
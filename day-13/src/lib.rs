@@ -0,0 +1,247 @@
+use common::{lines, Answer, Line, ParseError, Solution};
+
+pub mod generate;
+pub mod oracle;
+
+const DAY: u32 = 13;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+const TOKENS_A: i64 = 3;
+const TOKENS_B: i64 = 1;
+const OFFSET: i64 = 10000000000000;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn test_part_one() {
+        let machines = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&machines);
+        assert_eq!(result, 480);
+    }
+
+    #[test]
+    fn test_parallel_buttons() {
+        let machines = parse(
+            "Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=4, Y=4\n\n\
+             Button A: X+6, Y+4\nButton B: X+3, Y+2\nPrize: X=15, Y=10\n\n\
+             Button A: X+2, Y+2\nButton B: X+7, Y+7\nPrize: X=9, Y=10\n\n\
+             Button A: X+0, Y+0\nButton B: X+0, Y+5\nPrize: X=0, Y=15\n",
+        )
+        .unwrap();
+        let tokens: Vec<Option<i64>> = machines
+            .iter()
+            .map(|m| count_tokens(m.a_button, m.b_button, m.prize))
+            .collect();
+        assert_eq!(tokens, [Some(2), Some(5), None, Some(3)]);
+        // Pressing A is cheaper when it moves the claw more than three times as far as B
+        let a_button = Button { x: 9, y: 9 };
+        let b_button = Button { x: 2, y: 2 };
+        let prize = Prize { x: 20, y: 20 };
+        assert_eq!(count_tokens(a_button, b_button, prize), Some(7));
+        let backwards = "Button A: X+-1, Y+1\nButton B: X+2, Y+2\nPrize: X=4, Y=4\n";
+        assert!(matches!(parse(backwards), Err(error) if error.line == 1));
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day13>(
+            env!("CARGO_MANIFEST_DIR"),
+            &EXAMPLES,
+            &["480", "875318608908"],
+        );
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day13>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Button {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Clone, Copy)]
+pub struct Prize {
+    x: i64,
+    y: i64,
+}
+
+pub struct Machine {
+    a_button: Button,
+    b_button: Button,
+    prize: Prize,
+}
+
+// Read the X and Y values of a line like "Button A: X+94, Y+34"
+fn read_units(line: &Line, sign: &str) -> Result<(i64, i64), ParseError> {
+    let values = match line.text.split_once(":") {
+        Some((_, values)) => values,
+        None => return Err(line.missing("':' separator")),
+    };
+    let mut units: Vec<i64> = vec![];
+    for value in values.split(",") {
+        match value.split_once(sign) {
+            Some((_, number)) => units.push(line.parse(number)?),
+            None => return Err(line.error(value.trim(), "invalid value")),
+        }
+    }
+    if units.len() != 2 {
+        return Err(line.error(values.trim(), "expected X and Y values"));
+    }
+    Ok((units[0], units[1]))
+}
+
+fn read_button_line(line: &Line) -> Result<Button, ParseError> {
+    let (x, y) = read_units(line, "+")?;
+    if x < 0 || y < 0 {
+        return Err(line.error(line.text, "buttons can't move the claw backwards"));
+    }
+    Ok(Button { x, y })
+}
+
+fn read_prize_line(line: &Line) -> Result<Prize, ParseError> {
+    let (x, y) = read_units(line, "=")?;
+    Ok(Prize { x, y })
+}
+
+// Count the minimum number of tokens needed to get the prize with buttons that move the claw in
+// the same direction
+fn count_tokens_parallel(a_button: Button, b_button: Button, prize: Prize) -> Option<i64> {
+    // Solve along one axis where the buttons move the claw, and check the other one later
+    let (a, b, target) = match (a_button.x, b_button.x) {
+        (0, 0) => (a_button.y, b_button.y, prize.y),
+        _ => (a_button.x, b_button.x, prize.x),
+    };
+    let wins = |a_presses: i64, b_presses: i64| {
+        let x = a_presses * a_button.x + b_presses * b_button.x;
+        let y = a_presses * a_button.y + b_presses * b_button.y;
+        (a_presses >= 0 && b_presses >= 0 && x == prize.x && y == prize.y)
+            .then_some(TOKENS_A * a_presses + TOKENS_B * b_presses)
+    };
+    // A button that doesn't move the claw is never worth pressing
+    match (a, b) {
+        (0, 0) => return wins(0, 0),
+        (0, _) => return (target % b == 0).then(|| wins(0, target / b)).flatten(),
+        (_, 0) => return (target % a == 0).then(|| wins(target / a, 0)).flatten(),
+        _ => (),
+    }
+    // Pressing one button more times means pressing the other one fewer times, and the cost
+    // changes by the same amount on each step, so the cheapest way presses one of them as few
+    // times as possible. The fewest presses of a button are lower than the move of the other one.
+    let fewest_a = (0..b).find(|n| (target - n * a) % b == 0);
+    let fewest_b = (0..a).find(|n| (target - n * b) % a == 0);
+    let candidates = [
+        fewest_a.and_then(|n| wins(n, (target - n * a) / b)),
+        fewest_b.and_then(|n| wins((target - n * b) / a, n)),
+    ];
+    candidates.into_iter().flatten().min()
+}
+
+// Count the minimum number of tokens needed to get the prize
+fn count_tokens(a_button: Button, b_button: Button, prize: Prize) -> Option<i64> {
+    // Solve the linear equations system to get the number of presses of each button needed to get
+    // the prize.
+    let det = a_button.x * b_button.y - b_button.x * a_button.y;
+    if det == 0 {
+        return count_tokens_parallel(a_button, b_button, prize);
+    }
+    let mut a_presses = prize.x * b_button.y - b_button.x * prize.y;
+    let mut b_presses = -prize.x * a_button.y + a_button.x * prize.y;
+    // Check if it's possible to get the prize on that machine
+    if (a_presses % det != 0) | (b_presses % det != 0) {
+        return None;
+    }
+    a_presses /= det;
+    b_presses /= det;
+    // If we need to press buttons negative times, the machine doesn't have a solution
+    if (a_presses < 0) | (b_presses < 0) {
+        return None;
+    }
+    let tokens = TOKENS_A * a_presses + TOKENS_B * b_presses;
+    Some(tokens)
+}
+
+pub fn parse(content: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut lines = lines(DAY, content);
+    let mut machines = vec![];
+    while let Some(line) = lines.next() {
+        let a_button = read_button_line(&line)?;
+        let b_button = read_button_line(&lines.expect("button B")?)?;
+        let prize = read_prize_line(&lines.expect("prize")?)?;
+        machines.push(Machine {
+            a_button,
+            b_button,
+            prize,
+        });
+        if lines.next().is_none() {
+            break;
+        }
+    }
+    Ok(machines)
+}
+
+/// Add up the fewest tokens needed to win all the prizes that can be won, once they get moved by
+/// the offset.
+pub(crate) fn get_total_number_of_tokens(machines: &[Machine], offset: i64) -> i64 {
+    let mut result = 0;
+    for machine in machines.iter() {
+        let mut prize = machine.prize;
+        prize.x += offset;
+        prize.y += offset;
+        if let Some(tokens) = count_tokens(machine.a_button, machine.b_button, prize) {
+            result += tokens
+        };
+    }
+    result
+}
+
+pub fn solve_part_one(machines: &[Machine]) -> i64 {
+    get_total_number_of_tokens(machines, 0)
+}
+
+pub fn solve_part_two(machines: &[Machine]) -> i64 {
+    get_total_number_of_tokens(machines, OFFSET)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
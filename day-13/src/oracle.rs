@@ -0,0 +1,100 @@
+use crate::{Machine, Prize, TOKENS_A, TOKENS_B};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use crate::{get_total_number_of_tokens, parse, solve_part_one};
+    use common::{check_differential, without_each};
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+";
+
+    /// Shrink the input by removing one of the machines.
+    fn shrink(input: &str) -> Vec<String> {
+        let machines: Vec<&str> = input.trim_end().split("\n\n").collect();
+        let candidates = without_each(&machines)
+            .iter()
+            .filter(|machines| !machines.is_empty())
+            .map(|machines| machines.join("\n\n"))
+            .collect();
+        candidates
+    }
+
+    #[test]
+    fn test_oracle_example() {
+        let machines = parse(EXAMPLE).unwrap();
+        assert_eq!(total_tokens(&machines, Some(100), 0), 280);
+    }
+
+    #[test]
+    fn test_part_one_against_oracle() {
+        check_differential(
+            300,
+            10,
+            generate,
+            shrink,
+            |input| solve_part_one(&parse(input).unwrap()),
+            |input| total_tokens(&parse(input).unwrap(), None, 0),
+        );
+    }
+
+    #[test]
+    fn test_offset_against_oracle() {
+        // The offset of part two is too far for the oracle to try every number of presses, so the
+        // prizes get moved by a smaller one
+        check_differential(
+            100,
+            10,
+            generate,
+            shrink,
+            |input| get_total_number_of_tokens(&parse(input).unwrap(), 1000),
+            |input| total_tokens(&parse(input).unwrap(), None, 1000),
+        );
+    }
+}
+
+/// Try every number of presses of the A button, and return the cheapest way to win the prize.
+///
+/// Each button can be pressed at most `max_presses` times, if given.
+fn min_tokens(machine: &Machine, max_presses: Option<i64>, offset: i64) -> Option<i64> {
+    let (a, b) = (machine.a_button, machine.b_button);
+    let prize = Prize {
+        x: machine.prize.x + offset,
+        y: machine.prize.y + offset,
+    };
+    let mut tokens = None;
+    for a_presses in 0..=prize.x / a.x {
+        let (rest_x, rest_y) = (prize.x - a_presses * a.x, prize.y - a_presses * a.y);
+        if rest_x % b.x != 0 {
+            continue;
+        }
+        let b_presses = rest_x / b.x;
+        if b_presses * b.y != rest_y {
+            continue;
+        }
+        if max_presses.is_some_and(|max| a_presses > max || b_presses > max) {
+            continue;
+        }
+        let cost = TOKENS_A * a_presses + TOKENS_B * b_presses;
+        tokens = Some(tokens.map_or(cost, |t: i64| t.min(cost)));
+    }
+    tokens
+}
+
+/// Add up the fewest tokens needed to win all the prizes that can be won, once they get moved by
+/// the offset.
+pub fn total_tokens(machines: &[Machine], max_presses: Option<i64>, offset: i64) -> i64 {
+    let total = machines
+        .iter()
+        .filter_map(|machine| min_tokens(machine, max_presses, offset))
+        .sum();
+    total
+}
@@ -0,0 +1,49 @@
+use std::error::Error;
+
+use grid::frames::{Frames, Rgb};
+
+use crate::{parse, solve_part_two, Map, Position, TILES};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use common::Rng;
+    use grid::frames::Palette;
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_animate() {
+        let input = generate(&mut Rng::new(3), 200);
+        let robots = parse(&input).unwrap();
+        let dir = env::temp_dir().join(format!("day-14-frames-{}", process::id()));
+        let mut frames = Frames::new(&dir, Palette::new(PALETTE)).unwrap();
+        frames.set_every(100);
+        animate(&input, &mut frames).unwrap();
+        let n_steps = solve_part_two(&robots, TILES) as usize + 1;
+        // Every hundredth second is written, and the last one too
+        let last_written = (n_steps - 1).is_multiple_of(100);
+        assert_eq!(
+            frames.written(),
+            n_steps.div_ceil(100) + usize::from(!last_written)
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Colors of the empty tiles and the ones with robots.
+pub const PALETTE: &[(char, Rgb)] = &[('.', [10, 30, 20]), ('#', [80, 220, 110])];
+
+/// Record the positions of the robots every second, until they draw the easter egg.
+pub fn animate(content: &str, frames: &mut Frames) -> Result<(), Box<dyn Error>> {
+    let robots = parse(content)?;
+    let easter_egg_time = solve_part_two(&robots, TILES);
+    let mut map = Map::new(TILES);
+    for time in 0..=easter_egg_time {
+        let positions: Vec<Position> = robots.iter().map(|r| r.get_position(time, TILES)).collect();
+        map = Map::new_from(&positions, TILES);
+        frames.record(&map.map)?;
+    }
+    frames.finish(&map.map)?;
+    Ok(())
+}
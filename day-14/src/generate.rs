@@ -0,0 +1,57 @@
+use common::Rng;
+
+use crate::TILES;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 500);
+        let robots = parse(&input).unwrap();
+        assert_eq!(robots.len(), 500);
+        assert!(solve_part_one(&robots, TILES) > 0);
+        assert!(solve_part_two(&robots, TILES) > 0);
+    }
+}
+
+const FRAME_WIDTH: usize = 31;
+const FRAME_HEIGHT: usize = 33;
+
+/// Generate a puzzle input with `size` robots.
+///
+/// The first robots draw the frame of the easter egg at a random time, while the rest of them
+/// move at random. `size` is raised if needed to draw the whole frame (128 robots).
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (TILES.x as i64, TILES.y as i64);
+    let time = rng.range(1, width * height);
+    let (left, top) = (
+        rng.range(0, width - FRAME_WIDTH as i64),
+        rng.range(0, height - FRAME_HEIGHT as i64),
+    );
+    let mut frame = vec![];
+    for i in 0..FRAME_WIDTH as i64 {
+        frame.push((left + i, top));
+        frame.push((left + i, top + FRAME_HEIGHT as i64 - 1));
+    }
+    for j in 1..FRAME_HEIGHT as i64 - 1 {
+        frame.push((left, top + j));
+        frame.push((left + FRAME_WIDTH as i64 - 1, top + j));
+    }
+    let mut input = String::new();
+    for i in 0..size.max(frame.len()) {
+        let (vx, vy) = (rng.range(-100, 101), rng.range(-100, 101));
+        let (x, y) = match frame.get(i) {
+            // Move the robot backwards from its place in the frame
+            Some((x, y)) => (
+                (x - vx * time).rem_euclid(width),
+                (y - vy * time).rem_euclid(height),
+            ),
+            None => (rng.range(0, width), rng.range(0, height)),
+        };
+        input.push_str(&format!("p={x},{y} v={vx},{vy}\n"));
+    }
+    input
+}
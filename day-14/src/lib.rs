@@ -0,0 +1,237 @@
+use common::{lines, parallel, Answer, Line, ParseError, Solution};
+use grid::Grid;
+use std::collections::HashMap;
+use std::fmt;
+
+pub mod animate;
+pub mod generate;
+
+const DAY: u32 = 14;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+const TARGET_CONSECUTIVE_ROBOTS: i32 = 30;
+
+/// Size of the bathroom where the robots move.
+#[derive(Debug, Clone, Copy)]
+pub struct Tiles {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// Size of the bathroom in the puzzle.
+pub const TILES: Tiles = Tiles { x: 101, y: 103 };
+
+/// Size of the bathroom in the example of the puzzle description.
+pub const EXAMPLE_TILES: Tiles = Tiles { x: 11, y: 7 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_recorded_answers, read_example};
+
+    #[test]
+    fn test_example() {
+        let content = read_example(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, 1);
+        let robots = parse(&content).unwrap();
+        assert_eq!(solve_part_one(&robots, EXAMPLE_TILES), 12);
+    }
+
+    #[test]
+    fn test_parallel() {
+        let robots = parse(&generate::generate(&mut common::Rng::new(1), 500)).unwrap();
+        let expected = solve_part_two(&robots, TILES);
+        parallel::set_workers(4);
+        assert_eq!(solve_part_two(&robots, TILES), expected);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day14>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+impl Position {
+    // Return the quadrant of the position
+    fn get_quadrant(&self, tiles: Tiles) -> Option<i32> {
+        let x_offset = self.x - tiles.x as i32 / 2;
+        let y_offset = self.y - tiles.y as i32 / 2;
+        match (x_offset, y_offset) {
+            (0, _) => None,
+            (_, 0) => None,
+            (1.., 1..) => Some(1),
+            (..0, 1..) => Some(2),
+            (..0, ..0) => Some(3),
+            (1.., ..0) => Some(4),
+        }
+    }
+}
+
+pub struct Robot {
+    initial: Position,
+    vx: i32,
+    vy: i32,
+}
+
+impl Robot {
+    // // Return the position of the robot after a given time
+    fn get_position(&self, time: i32, tiles: Tiles) -> Position {
+        Position {
+            x: (self.initial.x + self.vx * time).rem_euclid(tiles.x as i32),
+            y: (self.initial.y + self.vy * time).rem_euclid(tiles.y as i32),
+        }
+    }
+}
+
+struct Map {
+    map: Grid<char>,
+}
+
+impl Map {
+    fn new(tiles: Tiles) -> Self {
+        let map = Grid::new(tiles.x, tiles.y, '.');
+        Map { map }
+    }
+
+    fn new_from(positions: &[Position], tiles: Tiles) -> Self {
+        let mut map = Self::new(tiles);
+        map.fill(positions);
+        map
+    }
+
+    fn fill(&mut self, positions: &[Position]) {
+        for position in positions.iter() {
+            self.map[(position.x as usize, position.y as usize)] = '#';
+        }
+    }
+
+    // Count the maximum number of consecutive robots in any row
+    fn get_max_consecutive_robots(&self) -> i32 {
+        let max_consecutive_robots = self
+            .map
+            .rows()
+            .map(Self::_max_consecutive_robots_in_row)
+            .max()
+            .unwrap();
+        max_consecutive_robots
+    }
+
+    // Count maximum consecutive robots in a single row
+    fn _max_consecutive_robots_in_row(row: &[char]) -> i32 {
+        let (mut max_consecutive, mut tmp) = (0, 0);
+        for char in row {
+            match char {
+                '#' => tmp += 1,
+                _ => {
+                    if tmp > max_consecutive {
+                        max_consecutive = tmp
+                    }
+                    tmp = 0;
+                }
+            }
+        }
+        // Check by the end of the row
+        if tmp > max_consecutive {
+            max_consecutive = tmp
+        }
+        max_consecutive
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+// Read a pair of values like "p=0,4"
+fn read_pair(line: &Line, part: Option<&str>, prefix: &str) -> Result<(i32, i32), ParseError> {
+    let part = match part {
+        Some(part) => part,
+        None => return Err(line.missing(prefix)),
+    };
+    let values = match part.strip_prefix(prefix) {
+        Some(values) => values,
+        None => return Err(line.error(part, &format!("expected '{prefix}'"))),
+    };
+    match values.split_once(",") {
+        Some((x, y)) => Ok((line.parse(x)?, line.parse(y)?)),
+        None => Err(line.error(values, "expected two values")),
+    }
+}
+
+fn read_line(line: Line) -> Result<Robot, ParseError> {
+    let mut parts = line.text.split_whitespace();
+    let position = read_pair(&line, parts.next(), "p=")?;
+    let velocity = read_pair(&line, parts.next(), "v=")?;
+    Ok(Robot {
+        initial: Position {
+            x: position.0,
+            y: position.1,
+        },
+        vx: velocity.0,
+        vy: velocity.1,
+    })
+}
+
+pub fn parse(content: &str) -> Result<Vec<Robot>, ParseError> {
+    let robots = lines(DAY, content).map(read_line).collect();
+    robots
+}
+
+fn get_safety_factor(robots: &[Robot], n_steps: i32, tiles: Tiles) -> i32 {
+    let mut robots_per_quadrant: HashMap<i32, i32> = HashMap::new();
+    for robot in robots {
+        let position = robot.get_position(n_steps, tiles);
+        if let Some(quadrant) = position.get_quadrant(tiles) {
+            robots_per_quadrant
+                .entry(quadrant)
+                .and_modify(|x| *x += 1)
+                .or_insert(1);
+        };
+    }
+    let safety_factor = robots_per_quadrant.values().product();
+    safety_factor
+}
+
+pub fn solve_part_one(robots: &[Robot], tiles: Tiles) -> i32 {
+    let n_steps = 100;
+    get_safety_factor(robots, n_steps, tiles)
+}
+
+pub fn solve_part_two(robots: &[Robot], tiles: Tiles) -> i32 {
+    // Look for the first time the robots draw a long enough line
+    let draws_easter_egg = |time: usize| {
+        let positions: Vec<Position> = robots
+            .iter()
+            .map(|r| r.get_position(time as i32, tiles))
+            .collect();
+        let map = Map::new_from(&positions, tiles);
+        map.get_max_consecutive_robots() > TARGET_CONSECUTIVE_ROBOTS
+    };
+    let easter_egg_time = parallel::find_first(0..100_000, draws_easter_egg).unwrap_or(0);
+    easter_egg_time as i32
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input, TILES).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input, TILES).into()
+    }
+}
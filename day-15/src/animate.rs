@@ -0,0 +1,59 @@
+use std::error::Error;
+
+use grid::frames::{Frames, Rgb};
+
+use crate::{new_large_map, parse};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use grid::frames::Palette;
+    use std::{env, fs, process};
+
+    const EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn test_animate() {
+        let dir = env::temp_dir().join(format!("day-15-frames-{}", process::id()));
+        let mut frames = Frames::new(&dir, Palette::new(PALETTE)).unwrap();
+        frames.set_every(5);
+        animate(EXAMPLE, &mut frames).unwrap();
+        // Frames of the moves 0, 5 and 10, plus the last one
+        assert_eq!(frames.written(), 4);
+        let first = fs::read(dir.join("frame-00000.ppm")).unwrap();
+        assert!(first.starts_with(b"P6\n14 7\n255\n"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Colors of the floor, walls, both halves of the boxes and the robot.
+pub const PALETTE: &[(char, Rgb)] = &[
+    ('.', [30, 30, 30]),
+    ('#', [120, 120, 130]),
+    ('[', [190, 130, 60]),
+    (']', [160, 105, 45]),
+    ('@', [240, 60, 60]),
+];
+
+/// Record the robot pushing boxes around the scaled-up warehouse of part two, one frame per move.
+pub fn animate(content: &str, frames: &mut Frames) -> Result<(), Box<dyn Error>> {
+    let (warehouse, directions) = parse(content)?;
+    let (mut map, mut robot) = new_large_map(&warehouse);
+    frames.record(&map.snapshot(&robot))?;
+    for direction in directions.iter() {
+        map.move_robot_large(&mut robot, direction);
+        frames.record(&map.snapshot(&robot))?;
+    }
+    frames.finish(&map.snapshot(&robot))?;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use common::Rng;
+use grid::Grid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 20);
+        let (warehouse, directions) = parse(&input).unwrap();
+        assert_eq!(warehouse.width(), 20);
+        assert_eq!(directions.len(), 8 * 20 * 20);
+        assert!(solve_part_one(&warehouse, &directions) > 0);
+        assert!(solve_part_two(&warehouse, &directions) > 0);
+    }
+}
+
+const LINE_LENGTH: usize = 1000;
+
+/// Generate a square warehouse of `size` by `size` tiles (at least three by three) and the moves of
+/// the robot.
+///
+/// The warehouse is surrounded by walls, with some more walls and plenty of boxes inside. The
+/// robot attempts eight moves per tile of the warehouse.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut warehouse = Grid::new(size, size, '#');
+    for y in 1..size - 1 {
+        for x in 1..size - 1 {
+            warehouse[(x, y)] = match rng.below(10) {
+                0 => '#',
+                1..=4 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    warehouse[(size / 2, size / 2)] = '@';
+    let mut input = warehouse.to_string();
+
+    let moves = ['^', 'v', '<', '>'];
+    for i in 0..8 * size * size {
+        if i % LINE_LENGTH == 0 {
+            input.push('\n');
+        }
+        input.push(*rng.choose(&moves));
+    }
+    input.push('\n');
+    input
+}
@@ -0,0 +1,221 @@
+use common::{lines, Answer, ParseError, Solution};
+pub use grid::Direction;
+use grid::Grid;
+use map::Map;
+use robot::Robot;
+
+pub mod animate;
+pub mod generate;
+mod map;
+mod robot;
+
+const DAY: u32 = 15;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn test_part_one() {
+        let (warehouse, directions) = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&warehouse, &directions);
+        assert_eq!(result, 10092);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let (warehouse, directions) = parse(EXAMPLE).unwrap();
+        let result = solve_part_two(&warehouse, &directions);
+        assert_eq!(result, 9021);
+    }
+
+    #[test]
+    fn test_parse_invalid_direction() {
+        let error = parse("#####\n#@.O#\n#####\n\n<^^>\nv<x>\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (15, 6, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_parse_open_border() {
+        let error = parse("@.\n\n>>\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.text, "@");
+        let error = parse("#####\n#@.O#\n###.#\n\n<^^>\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 4, "."));
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day15>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["10092", "9021"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day15>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+pub type Warehouse = Grid<char>;
+
+pub fn parse(content: &str) -> Result<(Warehouse, Vec<Direction>), ParseError> {
+    // The map and the directions are separated by an empty line
+    let map_end = content.find("\n\n").unwrap_or(content.len());
+    let warehouse = Grid::parse_with(DAY, &content[..map_end], |c| {
+        "#.O@".contains(c).then_some(c)
+    })?;
+    // The robot and the boxes must stay inside the map, so it has to be surrounded by walls
+    let (width, height) = (warehouse.width(), warehouse.height());
+    let outside_wall = warehouse.iter().find(|((x, y), c)| {
+        let on_border = *x == 0 || *y == 0 || *x + 1 == width || *y + 1 == height;
+        on_border && **c != '#'
+    });
+    if let Some(((x, y), c)) = outside_wall {
+        return Err(ParseError::new(
+            DAY,
+            y + 1,
+            x + 1,
+            &c.to_string(),
+            "the map must be surrounded by walls",
+        ));
+    }
+    let mut directions: Vec<Direction> = vec![];
+    for line in lines(DAY, content).skip(warehouse.height() + 1) {
+        for (i, c) in line.text.chars().enumerate() {
+            match Direction::from_char(c) {
+                Some(direction) if "^v<>".contains(c) => directions.push(direction),
+                _ => return Err(line.error_at(i, "invalid direction")),
+            }
+        }
+    }
+    let n_robots = warehouse.iter().filter(|(_, c)| **c == '@').count();
+    if n_robots != 1 {
+        return Err(ParseError::new(
+            DAY,
+            1,
+            1,
+            "",
+            &format!("expected a single robot in the map, found {n_robots}"),
+        ));
+    }
+    Ok((warehouse, directions))
+}
+
+fn find_robot(warehouse: &Warehouse) -> Robot {
+    let (x, y) = warehouse.find(&'@').unwrap();
+    Robot {
+        x: x as i32,
+        y: y as i32,
+    }
+}
+
+fn new_map(warehouse: &Warehouse) -> (Map, Robot) {
+    let robot = find_robot(warehouse);
+    let map = warehouse.map(|c| match c {
+        '@' => '.',
+        c => *c,
+    });
+    (Map::new_from(map), robot)
+}
+
+fn new_large_map(warehouse: &Warehouse) -> (Map, Robot) {
+    let mut robot = find_robot(warehouse);
+    robot.x *= 2;
+    let mut map: Vec<Vec<char>> = vec![];
+    for line in warehouse.rows() {
+        let mut row: Vec<char> = vec![];
+        for char in line.iter() {
+            match char {
+                '#' => {
+                    row.push('#');
+                    row.push('#')
+                }
+                'O' => {
+                    row.push('[');
+                    row.push(']')
+                }
+                '.' | '@' => {
+                    row.push('.');
+                    row.push('.')
+                }
+                e => panic!("invalid character {}", e),
+            }
+        }
+        map.push(row)
+    }
+    (Map::new_from(Grid::from_rows(map)), robot)
+}
+
+fn move_robot(map: &mut Map, robot: &mut Robot, directions: &[Direction], large: bool) {
+    for direction in directions.iter() {
+        match large {
+            true => map.move_robot_large(robot, direction),
+            false => map.move_robot(robot, direction),
+        }
+    }
+}
+
+fn get_gps_after_moves(warehouse: &Warehouse, directions: &[Direction], large: bool) -> i32 {
+    let (mut map, mut robot) = match large {
+        true => new_large_map(warehouse),
+        false => new_map(warehouse),
+    };
+    move_robot(&mut map, &mut robot, directions, large);
+    map.get_gps(large)
+}
+
+pub fn solve_part_one(warehouse: &Warehouse, directions: &[Direction]) -> i32 {
+    get_gps_after_moves(warehouse, directions, false)
+}
+
+pub fn solve_part_two(warehouse: &Warehouse, directions: &[Direction]) -> i32 {
+    get_gps_after_moves(warehouse, directions, true)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (warehouse, directions) = input;
+        get_gps_after_moves(warehouse, directions, false).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        let (warehouse, directions) = input;
+        get_gps_after_moves(warehouse, directions, true).into()
+    }
+}
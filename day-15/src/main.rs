@@ -1,151 +1,29 @@
-use directions::Direction;
-use map::Map;
-use robot::Robot;
-use std::fs;
+use common::Args;
+use day_15::{parse, solve_part_one, solve_part_two, EXAMPLES};
+use std::process;
 
-mod directions;
-mod map;
-mod robot;
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let fname = "data/test_input";
-        let result = solve_part_one(fname);
-        assert_eq!(result, 10092);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let fname = "data/test_input";
-        let result = solve_part_two(fname);
-        assert_eq!(result, 9021);
-    }
-}
-
-fn read_file(fname: &str) -> (Map, Robot, Vec<Direction>) {
-    let content = fs::read_to_string(fname).unwrap();
-    let mut read_map = true;
-    let mut map: Vec<Vec<char>> = vec![];
-    let mut directions: Vec<Direction> = vec![];
-    let mut robot: Robot = Robot::new();
-    for (j, line) in content.lines().enumerate() {
-        if line.is_empty() {
-            read_map = false;
-            continue;
-        }
-        if read_map {
-            let row = line
-                .chars()
-                .enumerate()
-                .map(|(i, c)| match c {
-                    '@' => {
-                        robot.x = i as i32;
-                        robot.y = j as i32;
-                        '.'
-                    }
-                    c => c,
-                })
-                .collect();
-            map.push(row)
-        } else {
-            directions.extend(
-                line.chars()
-                    .map(|c| Direction::from(&c))
-                    .collect::<Vec<Direction>>(),
-            )
-        }
-    }
-    (Map::new_from(map), robot, directions)
-}
-
-fn read_file_large_map(fname: &str) -> (Map, Robot, Vec<Direction>) {
-    let content = fs::read_to_string(fname).unwrap();
-    let mut read_map = true;
-    let mut map: Vec<Vec<char>> = vec![];
-    let mut directions: Vec<Direction> = vec![];
-    let mut robot: Robot = Robot::new();
-    for (j, line) in content.lines().enumerate() {
-        if line.is_empty() {
-            read_map = false;
-            continue;
+fn main() {
+    let args = Args::from_env(2);
+    let content = match args.read_input(&EXAMPLES, args.first_part()) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("Couldn't read input: {e}");
+            process::exit(1);
         }
-        if read_map {
-            let mut row: Vec<char> = vec![];
-            for (i, char) in line.chars().enumerate() {
-                match char {
-                    '#' => {
-                        row.push('#');
-                        row.push('#')
-                    }
-                    'O' => {
-                        row.push('[');
-                        row.push(']')
-                    }
-                    '.' => {
-                        row.push('.');
-                        row.push('.')
-                    }
-                    '@' => {
-                        row.push('.');
-                        row.push('.');
-                        robot.x = 2 * i as i32;
-                        robot.y = j as i32;
-                    }
-                    e => panic!("invalid character {}", e),
-                }
-            }
-            map.push(row)
-        } else {
-            directions.extend(
-                line.chars()
-                    .map(|c| Direction::from(&c))
-                    .collect::<Vec<Direction>>(),
-            )
+    };
+    let (warehouse, directions) = match parse(&content) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
         }
+    };
+    if args.solves(1) {
+        let result = solve_part_one(&warehouse, &directions);
+        println!("Solution to part one: {result}");
     }
-    (Map::new_from(map), robot, directions)
-}
-
-fn solve_part_one(fname: &str) -> i32 {
-    let (mut map, mut robot, directions) = read_file(fname);
-    println!("Start state");
-    map.print(&robot);
-    for direction in directions.iter() {
-        map.move_robot(&mut robot, direction);
-    }
-    println!("\nEnd state");
-    map.print(&robot);
-    map.get_gps(false)
-}
-
-fn solve_part_two(fname: &str) -> i32 {
-    let (mut map, mut robot, directions) = read_file_large_map(fname);
-    println!("Start state");
-    map.print(&robot);
-    for direction in directions.iter() {
-        map.move_robot_large(&mut robot, direction);
+    if args.solves(2) {
+        let result = solve_part_two(&warehouse, &directions);
+        println!("Solution to part two: {result}");
     }
-    println!("\nEnd state");
-    map.print(&robot);
-    map.get_gps(true)
-}
-
-fn main() {
-    let fname = "data/input";
-
-    println!("------------");
-    println!("| Part one |");
-    println!("------------");
-    let result = solve_part_one(fname);
-    println!("Solution to part one: {result}");
-
-    println!("\n------------");
-    println!("| Part two |");
-    println!("------------");
-    let result = solve_part_two(fname);
-    println!("Solution to part two: {result}");
 }
@@ -1,36 +1,41 @@
 use crate::Direction;
 use crate::Robot;
+use grid::Grid;
+
+// Return the position next to (x, y) in the given direction
+fn next_position(direction: &Direction, x: i32, y: i32) -> (i32, i32) {
+    let (dx, dy) = direction.delta();
+    (x + dx as i32, y + dy as i32)
+}
 
 pub struct Map {
-    pub map: Vec<Vec<char>>,
-    nrows: i32,
-    ncols: i32,
+    pub map: Grid<char>,
 }
 
 impl Map {
-    // Create new MapLarge
-    pub fn new_from(map: Vec<Vec<char>>) -> Self {
-        let nrows = map[0].len() as i32;
-        let ncols = map.len() as i32;
-        Self { map, nrows, ncols }
+    pub fn new_from(map: Grid<char>) -> Self {
+        Self { map }
     }
 
     fn get(&self, x: i32, y: i32) -> Option<char> {
-        if (x < 0) | (x >= self.nrows) | (y < 0) | (y >= self.ncols) {
+        if (x < 0) | (y < 0) {
             return None;
         }
-        Some(self.map[y as usize][x as usize])
+        self.map.get(x as usize, y as usize).copied()
     }
 
     fn write(&mut self, x: i32, y: i32, value: char) {
-        if (x < 0) | (x >= self.nrows) | (y < 0) | (y >= self.ncols) {
+        if (x < 0) | (y < 0) {
             panic!("trying to write outside the map")
         }
-        self.map[y as usize][x as usize] = value;
+        match self.map.get_mut(x as usize, y as usize) {
+            Some(tile) => *tile = value,
+            None => panic!("trying to write outside the map"),
+        }
     }
 
     pub fn move_robot(&mut self, robot: &mut Robot, direction: &Direction) {
-        let (x_next, y_next) = direction.get_next_position(robot.x, robot.y);
+        let (x_next, y_next) = next_position(direction, robot.x, robot.y);
         match self.get(x_next, y_next) {
             Some('.') => {
                 robot.x = x_next;
@@ -50,7 +55,7 @@ impl Map {
 
     // Move robot in map with large boxes
     pub fn move_robot_large(&mut self, robot: &mut Robot, direction: &Direction) {
-        let (x_next, y_next) = direction.get_next_position(robot.x, robot.y);
+        let (x_next, y_next) = next_position(direction, robot.x, robot.y);
         match self.get(x_next, y_next) {
             Some('.') => {
                 robot.x = x_next;
@@ -76,21 +81,21 @@ impl Map {
             Some(_) => panic!("Tried to move a tile that doesn't have a box: {} {}", x, y),
             None => panic!("Invalid location: {}, {}", x, y),
         }
-        let (x_next, y_next) = direction.get_next_position(x, y);
+        let (x_next, y_next) = next_position(direction, x, y);
         match self.get(x_next, y_next) {
             Some('O') => {
                 let moved = self.move_box(direction, x_next, y_next);
                 // Move box only if the next one moved as well
                 if moved {
-                    self.map[y as usize][x as usize] = '.';
-                    self.map[y_next as usize][x_next as usize] = 'O';
+                    self.write(x, y, '.');
+                    self.write(x_next, y_next, 'O');
                 }
                 return moved;
             }
             Some('.') => {
                 // Move box
-                self.map[y as usize][x as usize] = '.';
-                self.map[y_next as usize][x_next as usize] = 'O';
+                self.write(x, y, '.');
+                self.write(x_next, y_next, 'O');
                 return true;
             }
             Some('#') => return false,
@@ -106,7 +111,7 @@ impl Map {
             None => panic!("Invalid location: {}, {}", x, y),
         };
         return match (box_side, direction) {
-            ('[', Direction::Right) => {
+            ('[', Direction::East) => {
                 let x_next = x + 2;
                 match self.get(x_next, y) {
                     Some('#') => false,
@@ -116,7 +121,7 @@ impl Map {
                     None => panic!("Invalid location: {}, {}", x, y),
                 }
             }
-            (']', Direction::Left) => {
+            (']', Direction::West) => {
                 let x_next = x - 2;
                 match self.get(x_next, y) {
                     Some('#') => false,
@@ -126,8 +131,8 @@ impl Map {
                     None => panic!("Invalid location: {}, {}", x, y),
                 }
             }
-            ('[', Direction::Up) | ('[', Direction::Down) => {
-                let (_, y_next) = direction.get_next_position(x, y);
+            ('[', Direction::North) | ('[', Direction::South) => {
+                let (_, y_next) = next_position(direction, x, y);
                 let left = match self.get(x, y_next) {
                     Some(c) => c,
                     None => panic!(""),
@@ -150,7 +155,7 @@ impl Map {
                     (l, r) => panic!("invalid {}, {}", l, r),
                 }
             }
-            (']', Direction::Up) | (']', Direction::Down) => {
+            (']', Direction::North) | (']', Direction::South) => {
                 self.can_move_large_box(direction, x - 1, y)
             }
             (_, _) => {
@@ -175,7 +180,7 @@ impl Map {
             None => panic!("Invalid location: {}, {}", x, y),
         };
         match (box_side, direction) {
-            ('[', Direction::Right) => {
+            ('[', Direction::East) => {
                 if let Some('[') = self.get(x + 2, y) {
                     self.move_large_box(direction, x + 2, y);
                 }
@@ -183,7 +188,7 @@ impl Map {
                 self.write(x + 1, y, '[');
                 self.write(x + 2, y, ']');
             }
-            (']', Direction::Left) => {
+            (']', Direction::West) => {
                 if let Some(']') = self.get(x - 2, y) {
                     self.move_large_box(direction, x - 2, y);
                 }
@@ -191,9 +196,9 @@ impl Map {
                 self.write(x - 1, y, ']');
                 self.write(x - 2, y, '[');
             }
-            ('[', Direction::Up) | ('[', Direction::Down) => {
+            ('[', Direction::North) | ('[', Direction::South) => {
                 // todo
-                let (_, y_next) = direction.get_next_position(x, y);
+                let (_, y_next) = next_position(direction, x, y);
                 let left = match self.get(x, y_next) {
                     Some(c) => c,
                     None => panic!(""),
@@ -223,7 +228,7 @@ impl Map {
                 self.write(x, y_next, '[');
                 self.write(x + 1, y_next, ']');
             }
-            (']', Direction::Up) | (']', Direction::Down) => {
+            (']', Direction::North) | (']', Direction::South) => {
                 self.move_large_box(direction, x - 1, y);
             }
             (_, _) => {
@@ -236,20 +241,11 @@ impl Map {
         true
     }
 
-    pub fn print(&self, robot: &Robot) {
+    // Return a copy of the map with the robot drawn on it
+    pub fn snapshot(&self, robot: &Robot) -> Grid<char> {
         let mut map = self.map.clone();
-        map[robot.y as usize][robot.x as usize] = '@';
-        let text = map
-            .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|c| c.to_string())
-                    .collect::<Vec<String>>()
-                    .join("")
-            })
-            .collect::<Vec<String>>()
-            .join("\n");
-        println!("{}", text);
+        map[(robot.x as usize, robot.y as usize)] = '@';
+        map
     }
 
     // Return the sum of the GPS coordinates of every box in the map
@@ -259,11 +255,9 @@ impl Map {
             false => 'O',
         };
         let mut gps = 0;
-        for (j, row) in self.map.iter().enumerate() {
-            for (i, char) in row.iter().enumerate() {
-                if *char == target {
-                    gps += (i + 100 * j) as i32;
-                }
+        for ((i, j), char) in self.map.iter() {
+            if *char == target {
+                gps += (i + 100 * j) as i32;
             }
         }
         gps
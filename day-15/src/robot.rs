@@ -2,9 +2,3 @@ pub struct Robot {
     pub x: i32,
     pub y: i32,
 }
-
-impl Robot {
-    pub fn new() -> Self {
-        Robot { x: 0, y: 0 }
-    }
-}
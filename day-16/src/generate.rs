@@ -0,0 +1,58 @@
+use common::Rng;
+use grid::{Direction, Grid};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 41);
+        let maze = parse(&input).unwrap();
+        assert!(solve_part_one(&maze) > 0);
+    }
+}
+
+/// Generate a square maze of `size` by `size` tiles, where `size` is rounded up to an odd number.
+///
+/// The maze is carved with a random depth-first search, so it starts with a single path between
+/// any two tiles. Then some walls get knocked down to create alternative paths. The start tile is
+/// in the bottom left corner and the end tile in the top right one, like in the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut maze = Grid::new(size, size, '#');
+    let start = (1, size - 2);
+    maze[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&current) = stack.last() {
+        // Look for unvisited cells two steps away
+        let candidates: Vec<_> = Direction::CARDINAL
+            .iter()
+            .filter_map(|direction| {
+                let wall = maze.next(current, *direction)?;
+                let cell = maze.next(wall, *direction)?;
+                // Keep the outer walls
+                let inside = cell.0 < size - 1 && cell.1 < size - 1;
+                (inside && maze[cell] == '#').then_some((wall, cell))
+            })
+            .collect();
+        if candidates.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (wall, cell) = *rng.choose(&candidates);
+        maze[wall] = '.';
+        maze[cell] = '.';
+        stack.push(cell);
+    }
+    // Remove some of the inner walls to make loops
+    for _ in 0..size * size / 50 {
+        let x = rng.range(1, size as i64 - 1) as usize;
+        let y = rng.range(1, size as i64 - 1) as usize;
+        maze[(x, y)] = '.';
+    }
+    maze[start] = 'S';
+    maze[(size - 2, 1)] = 'E';
+    maze.to_string()
+}
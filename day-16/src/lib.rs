@@ -0,0 +1,157 @@
+use common::{Answer, ParseError, Solution};
+use grid::search::dijkstra;
+use grid::{Direction, Point};
+
+pub use crate::maze::Maze;
+
+pub mod generate;
+mod maze;
+
+const DAY: u32 = 16;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 1] = ["data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const EXAMPLE_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn test_part_one_example_one() {
+        let maze = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&maze);
+        assert_eq!(result, 7036);
+    }
+    #[test]
+    fn test_part_one_example_two() {
+        let maze = parse(EXAMPLE_2).unwrap();
+        let result = solve_part_one(&maze);
+        assert_eq!(result, 11048);
+    }
+
+    #[test]
+    fn test_parse_unreachable_end() {
+        let Err(error) = parse("#####\n#E#.#\n###.#\n#S..#\n#####\n") else {
+            panic!("the end tile shouldn't be reachable");
+        };
+        assert_eq!((error.day, error.line, error.column), (16, 2, 2));
+        assert_eq!(error.text, "E");
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day16>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["7036"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day16>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Position and orientation of the reindeer.
+///
+/// Two tiles in the same location but different orientation should be treated as different
+/// states. Failing to do so would not result in the lowest score.
+type State = (Point, Direction);
+
+// Return the states that can be reached from the given one, along with the cost of moving there
+fn get_neighbors(maze: &Maze, (position, orientation): &State) -> Vec<(State, u64)> {
+    // The reindeer can move forward, or turn and then move
+    let moves = [
+        (*orientation, 1),
+        (orientation.turn_right(), 1 + 1000),
+        (orientation.turn_left(), 1 + 1000),
+    ];
+    let mut neighbors = vec![];
+    for (orientation, score) in moves {
+        let Some(neighbor) = maze.map.next(*position, orientation) else {
+            continue;
+        };
+        // Skip if neighbor is a wall
+        if maze.map[neighbor] == '#' {
+            continue;
+        };
+        neighbors.push(((neighbor, orientation), score));
+    }
+    neighbors
+}
+
+fn get_lowest_score(maze: &Maze) -> Result<u32, &str> {
+    let start = (maze.get_start(), Direction::East);
+    let end = maze.get_end();
+    let search = dijkstra(
+        start,
+        |state| get_neighbors(maze, state),
+        |(position, _)| *position == end,
+    );
+    match search.cost() {
+        Some(score) => Ok(score as u32),
+        None => Err("Couldn't find path"),
+    }
+}
+
+pub fn parse(content: &str) -> Result<Maze, ParseError> {
+    Maze::parse(DAY, content)
+}
+
+pub fn solve_part_one(maze: &Maze) -> u32 {
+    // `parse` checks that the end can be reached
+    match get_lowest_score(maze) {
+        Ok(result) => result,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+}
@@ -1,32 +1,49 @@
-use std::fs;
+use common::ParseError;
+use grid::search::bfs;
+use grid::Grid;
 
 pub struct Maze {
-    pub map: Vec<Vec<char>>,
+    pub map: Grid<char>,
 }
 
 impl Maze {
-    // Create new maze from input file
-    pub fn new_from(fname: &str) -> Self {
-        let content = fs::read_to_string(fname).unwrap();
-        let mut map: Vec<Vec<char>> = vec![];
-        for line in content.lines() {
-            let row = line.chars().collect();
-            map.push(row);
+    // Create new maze from its text
+    pub fn parse(day: u32, content: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse_with(day, content, |c| "#.SE".contains(c).then_some(c))?;
+        // Check that start and end tiles are where we expect them
+        let n_rows = map.height();
+        if n_rows < 3 || !map.rows().nth(n_rows - 2).unwrap().contains(&'S') {
+            let message = "expected the start tile 'S' in the second to last row";
+            return Err(ParseError::new(day, n_rows.max(2) - 1, 1, "", message));
         }
-        Self { map }
+        if !map.rows().nth(1).unwrap().contains(&'E') {
+            let message = "expected the end tile 'E' in the second row";
+            return Err(ParseError::new(day, 2, 1, "", message));
+        }
+        // The end tile has to be reachable from the start one
+        let (start, end) = (map.find(&'S').unwrap(), map.find(&'E').unwrap());
+        let search = bfs(
+            start,
+            |point| {
+                map.neighbors4(*point)
+                    .filter(|neighbor| map[*neighbor] != '#')
+            },
+            |point| *point == end,
+        );
+        if search.path().is_none() {
+            let message = "the end tile can't be reached from the start";
+            return Err(ParseError::new(day, end.1 + 1, end.0 + 1, "E", message));
+        }
+        Ok(Self { map })
     }
 
     // Get start position
     pub fn get_start(&self) -> (usize, usize) {
-        let j = self.map.len() - 2; // S is always in the previous to last row
-        let i = self.map[j].iter().position(|c| *c == 'S').unwrap();
-        (i, j)
+        self.map.find(&'S').unwrap()
     }
 
     // Get end position
     pub fn get_end(&self) -> (usize, usize) {
-        let j = 1; // S is always in the second row
-        let i = self.map[j].iter().position(|c| *c == 'E').unwrap();
-        (i, j)
+        self.map.find(&'E').unwrap()
     }
 }
@@ -0,0 +1,36 @@
+use common::Rng;
+
+use crate::{parse, solve_part_two};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solve_part_one;
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 0);
+        let computer = parse(&input).unwrap();
+        assert_eq!(solve_part_one(&computer).len(), 8);
+        assert!(solve_part_two(&computer).is_some_and(|a| a > 0));
+    }
+}
+
+/// Generate the initial state of the computer and its program.
+///
+/// Programs follow the same structure as the ones of the puzzle, with random operands, so that
+/// part two can be solved. Their length is fixed, so `size` is ignored.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    loop {
+        let a = rng.range(8_i64.pow(7), 8_i64.pow(8));
+        let (first, second, ignored) = (rng.below(8), rng.below(8), rng.below(8));
+        let input = format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\n\
+             Program: 2,4,1,{first},7,5,4,{ignored},0,3,1,{second},5,5,3,0\n"
+        );
+        // Not every program can output itself
+        if solve_part_two(&parse(&input).unwrap()).is_some() {
+            return input;
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use common::{lines, Answer, ParseError, Solution};
+
+pub mod generate;
+pub mod oracle;
+
+const DAY: u32 = 17;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input_2"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const EXAMPLE_2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn test_simple_program_1() {
+        let (a, b, c) = (0, 0, 9);
+        let program = vec![2, 6];
+        let mut computer = ThreeBitsComputer::initialize(a, b, c, program);
+        let out = computer.run();
+        assert!(out.is_empty());
+        assert_eq!(computer.b, 1);
+    }
+
+    #[test]
+    fn test_simple_program_2() {
+        let (a, b, c) = (10, 0, 0);
+        let program = vec![5, 0, 5, 1, 5, 4];
+        let mut computer = ThreeBitsComputer::initialize(a, b, c, program);
+        let out = computer.run();
+        assert_eq!(out, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_simple_program_3() {
+        let (a, b, c) = (2024, 0, 0);
+        let program = vec![0, 1, 5, 4, 3, 0];
+        let mut computer = ThreeBitsComputer::initialize(a, b, c, program);
+        let out = computer.run();
+        assert_eq!(out, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(computer.a, 0);
+    }
+
+    #[test]
+    fn test_simple_program_4() {
+        let (a, b, c) = (0, 29, 0);
+        let program = vec![1, 7];
+        let mut computer = ThreeBitsComputer::initialize(a, b, c, program);
+        computer.run();
+        assert_eq!(computer.b, 26);
+    }
+
+    #[test]
+    fn test_simple_program_5() {
+        let (a, b, c) = (0, 2024, 43690);
+        let program = vec![4, 0];
+        let mut computer = ThreeBitsComputer::initialize(a, b, c, program);
+        computer.run();
+        assert_eq!(computer.b, 44354);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let computer = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&computer);
+        assert_eq!(result, vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let computer = parse(EXAMPLE_2).unwrap();
+        let result = solve_part_two(&computer);
+        assert_eq!(result, Some(117440));
+    }
+
+    #[test]
+    fn test_part_two_unsolved() {
+        // No quine exists, or the program doesn't follow the assumptions of the solution
+        let computer = ThreeBitsComputer::initialize(0, 0, 0, vec![5, 4, 0, 3, 3, 0]);
+        assert_eq!(solve_part_two(&computer), None);
+        let computer = ThreeBitsComputer::initialize(0, 0, 0, vec![0, 3, 5, 5, 3, 0]);
+        assert_eq!(solve_part_two(&computer), None);
+    }
+
+    #[test]
+    fn test_no_quine_with_leading_zero() {
+        // The last value is zero, so the highest digit of A would have to be zero as well, which
+        // makes the program halt one output early
+        let program = vec![5, 4, 0, 3, 3, 0];
+        let mut computer = ThreeBitsComputer::initialize(0, 0, 0, program);
+        assert_eq!(solve_for_a(&mut computer, 0, 5), None);
+    }
+
+    #[test]
+    fn test_follows_assumptions() {
+        assert!(follows_assumptions(&[0, 3, 5, 4, 3, 0]));
+        let program = [2, 4, 1, 3, 7, 5, 0, 3, 4, 1, 5, 5, 3, 0];
+        assert!(follows_assumptions(&program));
+        // B is read before it's set, A is divided twice, or the program jumps elsewhere
+        assert!(!follows_assumptions(&[0, 3, 5, 5, 3, 0]));
+        assert!(!follows_assumptions(&[0, 3, 0, 3, 5, 4, 3, 0]));
+        assert!(!follows_assumptions(&[0, 3, 5, 4, 3, 2]));
+    }
+
+    #[test]
+    fn test_parse_invalid_program() {
+        let error =
+            parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,9,3,0\n")
+                .unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (17, 5, 16));
+        assert_eq!(error.text, "9");
+        let error =
+            parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7,3,0\n")
+                .unwrap_err();
+        assert_eq!((error.line, error.column), (5, 16));
+        assert_eq!(error.message, "reserved combo operand");
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day17>(
+            env!("CARGO_MANIFEST_DIR"),
+            &EXAMPLES,
+            &["4,6,3,5,6,3,5,2,1,0", "117440"],
+        );
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day17>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ThreeBitsComputer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u8>,
+    pointer: usize,
+}
+
+impl ThreeBitsComputer {
+    fn initialize(a: u64, b: u64, c: u64, program: Vec<u8>) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            program,
+            pointer: 0,
+        }
+    }
+
+    /// Run a full program
+    ///
+    /// It halts when the pointer gets past the opcode or the operand of the last instruction.
+    fn run(&mut self) -> Vec<u8> {
+        let mut output: Vec<u8> = vec![];
+        while self.pointer + 1 < self.program.len() {
+            if let Some(o) = self.run_cycle() {
+                output.push(o)
+            };
+        }
+        output
+    }
+
+    /// Run one CPU cycle (execute a single instruction)
+    fn run_cycle(&mut self) -> Option<u8> {
+        let instruction = self.program[self.pointer];
+        let operand = self.program[self.pointer + 1];
+        self.pointer += 2;
+        match instruction {
+            0 => self.adv(operand),
+            1 => self.bxl(operand),
+            2 => self.bst(operand),
+            3 => self.jnz(operand),
+            4 => self.bxc(operand),
+            5 => return Some(self.out(operand)),
+            6 => self.bdv(operand),
+            7 => self.cdv(operand),
+            e => panic!("invalid instruction {e}"),
+        }
+        None
+    }
+
+    /// Run full iteration of the program
+    fn run_iteration(&mut self) -> u8 {
+        // Force pointer to be at zero at the start of the iteration.
+        self.pointer = 0;
+        let mut output = 0;
+        loop {
+            if let Some(o) = self.run_cycle() {
+                output = o
+            }
+            // Finish iteration when the pointer jumpted to zero or when it's outside the program.
+            if self.pointer == 0 || self.pointer >= self.program.len() - 1 {
+                break;
+            }
+        }
+        output
+    }
+
+    fn get_combo_operand(&self, operand: u8) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            7 => panic!("Found invalid combo operand 7"),
+            e => panic!("Found invalid combo operand '{e}'"),
+        }
+    }
+
+    /// Division between A and 2.pow(combo operand), truncate result and store in A.
+    fn adv(&mut self, operand: u8) {
+        let combo_operand = self.get_combo_operand(operand);
+        self.a = divide(self.a, combo_operand);
+    }
+
+    /// Bitwise XOR between B and literal operand. Result stored in B.
+    fn bxl(&mut self, operand: u8) {
+        self.b ^= operand as u64;
+    }
+
+    /// Modulo between combo operand and 8. Results stored in B.
+    fn bst(&mut self, operand: u8) {
+        let combo_operand = self.get_combo_operand(operand);
+        self.b = combo_operand % 8;
+    }
+
+    /// Nothing if a = 0. Else, jump pointer to the position given by the literal operand.
+    /// In such case, the pointer doesn't jumps two steps after running this instruction.
+    fn jnz(&mut self, operand: u8) {
+        if self.a == 0 {
+            return;
+        };
+        self.pointer = operand as usize;
+    }
+
+    /// Bitwise XOR between B and C. Result stored in B.
+    fn bxc(&mut self, _operand: u8) {
+        self.b ^= self.c;
+    }
+
+    /// Combo operand % 8 and then output the value
+    fn out(&mut self, operand: u8) -> u8 {
+        let combo_operand = self.get_combo_operand(operand);
+        (combo_operand % 8) as u8
+    }
+
+    /// Division between A and 2.pow(combo operand), truncate result and store in B.
+    fn bdv(&mut self, operand: u8) {
+        let combo_operand = self.get_combo_operand(operand);
+        self.b = divide(self.a, combo_operand);
+    }
+
+    /// Division between A and 2.pow(combo operand), truncate result and store in C.
+    fn cdv(&mut self, operand: u8) {
+        let combo_operand = self.get_combo_operand(operand);
+        self.c = divide(self.a, combo_operand);
+    }
+}
+
+/// Division between a value and 2.pow(exponent), which is zero if the exponent is 64 or larger.
+fn divide(value: u64, exponent: u64) -> u64 {
+    match exponent < 64 {
+        true => value >> exponent,
+        false => 0,
+    }
+}
+
+pub fn parse(content: &str) -> Result<ThreeBitsComputer, ParseError> {
+    let mut lines = lines(DAY, content);
+    let mut registers = [0, 0, 0];
+    loop {
+        let line = lines.expect("program")?;
+        if line.text.is_empty() {
+            break;
+        };
+        let (name, value) = match line.text.split_once(":") {
+            Some(parts) => parts,
+            None => return Err(line.missing("':' separator")),
+        };
+        let reg = name.split_whitespace().last().unwrap_or(name);
+        let value: u64 = line.parse(value)?;
+        match &reg.to_lowercase()[..] {
+            "a" => registers[0] = value,
+            "b" => registers[1] = value,
+            "c" => registers[2] = value,
+            _ => return Err(line.error(reg, "invalid register")),
+        }
+    }
+    let line = lines.expect("program")?;
+    let code = match line.text.split_once(":") {
+        Some((_, code)) => code,
+        None => return Err(line.missing("':' separator")),
+    };
+    let values: Vec<&str> = code.split(",").collect();
+    let mut program: Vec<u8> = vec![];
+    for value in values.iter() {
+        let number: u8 = line.parse(value)?;
+        if number > 7 {
+            return Err(line.error(value.trim(), "invalid 3-bit number"));
+        }
+        program.push(number);
+    }
+    if !program.len().is_multiple_of(2) {
+        return Err(line.missing("operand of the last instruction"));
+    }
+    // Combo operand 7 is reserved and never appears in valid programs
+    for (i, instruction) in program.chunks(2).enumerate() {
+        if matches!(instruction[0], 0 | 2 | 5 | 6 | 7) && instruction[1] == 7 {
+            return Err(line.error(values[2 * i + 1].trim(), "reserved combo operand"));
+        }
+    }
+    Ok(ThreeBitsComputer::initialize(
+        registers[0],
+        registers[1],
+        registers[2],
+        program,
+    ))
+}
+
+pub fn fmt_output(output: &[u8]) -> String {
+    let output: Vec<String> = output.iter().map(|c| format!("{c}")).collect();
+    output.join(",")
+}
+
+/// Find the value of A that would make the computer to produce the same program.
+///
+/// This is a recursive function. The `a` argument should be the possible solution of A from the
+/// previous step (or zero if it's the first step).
+/// The iteration argument should be the index of the iteration that will be tested (use
+/// `program.len() - 1` if  this is the first step).
+fn solve_for_a(computer: &mut ThreeBitsComputer, a: u64, iteration: i32) -> Option<u64> {
+    if iteration < 0 {
+        return Some(a);
+    }
+    let expected = computer.program[iteration as usize];
+    for next_bits in 0..=0b111 {
+        let a_try = a * 2u64.pow(3_u32) + next_bits;
+        // The program halts once A gets to zero, so it can't be zero before the last output
+        if a_try == 0 {
+            continue;
+        }
+        computer.a = a_try;
+        let output = computer.run_iteration();
+        if output == expected {
+            if let Some(s) = solve_for_a(computer, a_try, iteration - 1) {
+                return Some(s);
+            }
+        }
+    }
+    None
+}
+
+/// Return true if the program follows the assumptions of `solve_for_a`, listed in `solve_part_two`.
+fn follows_assumptions(program: &[u8]) -> bool {
+    let body = match program.split_last_chunk::<2>() {
+        Some((body, [3, 0])) => body,
+        _ => return false,
+    };
+    let (mut b_set, mut c_set) = (false, false);
+    let (mut divisions, mut outputs) = (0, 0);
+    for instruction in body.chunks(2) {
+        let (opcode, operand) = (instruction[0], instruction[1]);
+        let combo = matches!(opcode, 0 | 2 | 5 | 6 | 7);
+        // B and C have to be set on each iteration before they are read
+        let reads_b = matches!(opcode, 1 | 4) || (combo && operand == 5);
+        let reads_c = opcode == 4 || (combo && operand == 6);
+        if (combo && operand == 7) || (reads_b && !b_set) || (reads_c && !c_set) {
+            return false;
+        }
+        match (opcode, operand) {
+            (0, 3) => divisions += 1,
+            (0, _) | (3, _) => return false,
+            (1, _) | (2, _) | (4, _) | (6, _) => b_set = true,
+            (5, _) => outputs += 1,
+            (7, _) => c_set = true,
+            _ => (),
+        }
+    }
+    divisions == 1 && outputs == 1
+}
+
+pub fn solve_part_one(computer: &ThreeBitsComputer) -> Vec<u8> {
+    let mut computer = computer.clone();
+    computer.run()
+}
+
+/// Solve part two of the puzzle.
+///
+/// Apply a DFS recursive algorithm to construct A three-bits at a time.
+///
+/// This solution works under the following assumptions:
+///
+/// * An iteration is a single run of all instructions in the program code sequentially.
+///   We assume the program doesn't jump the pointer (besides the regular 2 steps) until the very
+///   last instruction.
+/// * The last instruction is `3,0`: jump pointer to the begining if A is not zero, halt otherwise.
+/// * Each iteration overwrites the values of registers B and C, so no state of B and C should be
+///   kept between iterations.
+/// * Each iteration "pops" the last three bits of A, and that's the only change applied to the
+///   register A. This means that it only runs adv (optcode 0) only once per iteration and always
+///   with an operator 3 (`0,3`).
+/// * Each iteration prints out a single time.
+///
+/// Both my personal input and the example provided by AoC satisfy these conditions.
+///
+/// With these assumptions in mind we can construct the bits of A by sets of three bits. We know
+/// that A will have 3N bits, where N is the number of iterations (i.e. number of values in the
+/// program code if A is a vaid solution). Since the last three bits of A are popped on each
+/// iteration, the iteration n doesn't need to know about the last 3n bits of A. This means that
+/// the last iteration (the ones that should print out `3` and leave A equal to zero) will only
+/// care about the first three bits of A (since all the other ones were already popped by the
+/// previous iterations). Therefore, we can start building A from its first three-bits, trying to
+/// find which ones can output the same number as the corresponding value in the program.
+/// By iterating over the numbers in the program code in reverse order (start from the last one
+/// -`3`-, then with the previous one -`0`- and so on), we can build a value of A that can produce
+/// the same code as the program.
+///
+/// There could be multiple solutions of three-bits for each one of the steps. But that doesn't
+/// mean they are all valid. So, we need to be able to discard solutions. Moreover, we need to find
+/// the lowest possible solution for A.
+/// To solve it, I implemented a DFS algorithm implemented in the `solve_for_a` recursive function.
+///
+/// Return None if the program doesn't follow the assumptions, or if no value of A works.
+pub fn solve_part_two(computer: &ThreeBitsComputer) -> Option<u64> {
+    if !follows_assumptions(&computer.program) {
+        return None;
+    }
+    let mut computer = computer.clone();
+    let n_iterations = computer.program.len() - 1;
+    // Start solve_for_a with a starting `a = 0` and `iteration` as the last one.
+    solve_for_a(&mut computer, 0, n_iterations as i32)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = ThreeBitsComputer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        match solve_part_two(input) {
+            Some(a) => a.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::ThreeBitsComputer;
+
+/// Number of cycles after which a program is considered to never halt.
+const MAX_CYCLES: usize = 200;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{follows_assumptions, parse, solve_for_a};
+    use common::{check_differential, without_each, Rng};
+
+    /// Values of A that the oracle tries, which are enough for programs of up to six values.
+    const BOUND: u64 = 8_u64.pow(6);
+
+    const EXAMPLE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    fn random_instruction(rng: &mut Rng) -> (u8, u8) {
+        let opcode = rng.below(8) as u8;
+        // Combo operand 7 is reserved
+        let operand = match opcode {
+            0 | 2 | 5 | 6 | 7 => rng.below(7),
+            _ => rng.below(8),
+        };
+        (opcode, operand as u8)
+    }
+
+    fn format_input(registers: [u64; 3], program: &[u8]) -> String {
+        let program: Vec<String> = program.iter().map(|value| value.to_string()).collect();
+        let [a, b, c] = registers;
+        format!(
+            "Register A: {a}\nRegister B: {b}\nRegister C: {c}\n\nProgram: {}\n",
+            program.join(",")
+        )
+    }
+
+    /// Generate a program with `size` random instructions, along with an output and a division
+    /// of A, and the jump to the start.
+    ///
+    /// The instructions and operands are random, so many of the programs don't follow the
+    /// assumptions of `solve_for_a`.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut body: Vec<(u8, u8)> = (1..size).map(|_| random_instruction(rng)).collect();
+        let output = (5, rng.below(7) as u8);
+        body.insert(rng.below(body.len() as u64 + 1) as usize, output);
+        let division = match rng.chance(0.5) {
+            true => (0, 3),
+            false => (0, rng.below(7) as u8),
+        };
+        body.insert(rng.below(body.len() as u64 + 1) as usize, division);
+        let mut program: Vec<u8> = body.iter().flat_map(|(op, x)| [*op, *x]).collect();
+        program.extend([3, 0]);
+        let registers = [rng.below(1000), rng.below(8), rng.below(8)];
+        format_input(registers, &program)
+    }
+
+    /// Remove each of the instructions.
+    fn shrink(input: &str) -> Vec<String> {
+        let computer = parse(input).unwrap();
+        let registers = [computer.a, computer.b, computer.c];
+        let instructions: Vec<&[u8]> = computer.program.chunks(2).collect();
+        let smaller = without_each(&instructions)
+            .into_iter()
+            .filter(|instructions| !instructions.is_empty())
+            .map(|instructions| format_input(registers, &instructions.concat()))
+            .collect();
+        smaller
+    }
+
+    #[test]
+    fn test_oracle_example() {
+        let computer = parse(EXAMPLE).unwrap();
+        assert_eq!(find_quine_a(&computer, BOUND), Some(117440));
+        assert_eq!(find_quine_a(&computer, 117440), None);
+    }
+
+    #[test]
+    fn test_oracle_any_program() {
+        // Programs that never halt or jump to the middle of an instruction don't stop the oracle
+        let program = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,1,3,0\n";
+        assert_eq!(find_quine_a(&parse(program).unwrap(), BOUND), None);
+        let program = "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 3,3,0,5,4,3\n";
+        assert_eq!(find_quine_a(&parse(program).unwrap(), BOUND), None);
+    }
+
+    #[test]
+    fn test_solve_for_a_against_oracle() {
+        // The solution only has to find A for the programs that follow its assumptions, and only
+        // the values below the bound are compared
+        check_differential(
+            200,
+            3,
+            generate,
+            shrink,
+            |input| {
+                let mut computer = parse(input).unwrap();
+                let last = computer.program.len() as i32 - 1;
+                follows_assumptions(&computer.program)
+                    .then(|| solve_for_a(&mut computer, 0, last).filter(|a| *a < BOUND))
+            },
+            |input| {
+                let computer = parse(input).unwrap();
+                follows_assumptions(&computer.program).then(|| find_quine_a(&computer, BOUND))
+            },
+        );
+    }
+}
+
+/// Run the program from the start until it halts, and return true if it outputs itself.
+///
+/// It stops early once an output differs from the program, or after `MAX_CYCLES`.
+fn outputs_itself(computer: &mut ThreeBitsComputer) -> bool {
+    let mut n_outputs = 0;
+    for _ in 0..MAX_CYCLES {
+        if computer.pointer + 1 >= computer.program.len() {
+            return n_outputs == computer.program.len();
+        }
+        if let Some(output) = computer.run_cycle() {
+            if computer.program.get(n_outputs) != Some(&output) {
+                return false;
+            }
+            n_outputs += 1;
+        }
+    }
+    false
+}
+
+/// Find the lowest value of A below `bound` that makes the program output itself, by trying all
+/// of them.
+///
+/// Registers B and C start with the values of the computer on every try.
+pub fn find_quine_a(computer: &ThreeBitsComputer, bound: u64) -> Option<u64> {
+    let mut computer = computer.clone();
+    let (b, c) = (computer.b, computer.c);
+    let quine_a = (0..bound).find(|a| {
+        (computer.a, computer.b, computer.c, computer.pointer) = (*a, b, c, 0);
+        outputs_itself(&mut computer)
+    });
+    quine_a
+}
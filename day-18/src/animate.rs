@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use grid::frames::{Frames, Rgb};
+use grid::{Grid, Point};
+
+use crate::{get_shortest_path, parse, MEMORY};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate;
+    use common::Rng;
+    use grid::frames::Palette;
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_animate() {
+        let input = generate(&mut Rng::new(1), 2000);
+        let dir = env::temp_dir().join(format!("day-18-frames-{}", process::id()));
+        let mut frames = Frames::new(&dir, Palette::new(PALETTE)).unwrap();
+        frames.set_every(1000);
+        animate(&input, &mut frames).unwrap();
+        // The start, the thousandth byte and the byte that blocks the exit
+        assert_eq!(frames.written(), 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Colors of the safe and corrupted positions, and of the shortest path to the exit.
+pub const PALETTE: &[(char, Rgb)] = &[
+    ('.', [15, 25, 45]),
+    ('#', [200, 60, 80]),
+    ('O', [90, 220, 230]),
+];
+
+fn snapshot(corrupted: &Grid<bool>, path: &[Point]) -> Grid<char> {
+    let mut tiles = corrupted.map(|c| match c {
+        true => '#',
+        false => '.',
+    });
+    for point in path {
+        tiles[*point] = 'O';
+    }
+    tiles
+}
+
+/// Record the bytes falling one at a time, along with the shortest path to the exit, until the
+/// exit gets blocked.
+pub fn animate(content: &str, frames: &mut Frames) -> Result<(), Box<dyn Error>> {
+    let bytes = parse(content, MEMORY)?;
+    let (start, end) = ((0, 0), (MEMORY.size - 1, MEMORY.size - 1));
+    let mut corrupted = Grid::new(MEMORY.size, MEMORY.size, false);
+    let mut path = get_shortest_path(&corrupted, start, end).unwrap_or_default();
+    frames.record(&snapshot(&corrupted, &path))?;
+    for byte in bytes.iter() {
+        corrupted[*byte] = true;
+        // Only look for a new path when the byte falls on the current one
+        if path.contains(byte) {
+            path = get_shortest_path(&corrupted, start, end).unwrap_or_default();
+        }
+        frames.record(&snapshot(&corrupted, &path))?;
+        if path.is_empty() {
+            break;
+        }
+    }
+    frames.finish(&snapshot(&corrupted, &path))?;
+    Ok(())
+}
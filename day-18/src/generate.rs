@@ -0,0 +1,60 @@
+use common::Rng;
+use grid::Grid;
+
+use crate::{get_shortest_path, MEMORY};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 2000);
+        let bytes = parse(&input, MEMORY).unwrap();
+        assert_eq!(bytes.len(), 2000);
+        assert!(solve_part_one(&bytes, MEMORY) >= 2 * (MEMORY.size as u32 - 1));
+        let blocking = solve_part_two(&bytes, MEMORY).unwrap();
+        assert!(bytes[MEMORY.fallen..].contains(&blocking));
+    }
+}
+
+/// Generate a puzzle input with the positions of `size` falling bytes.
+///
+/// The memory space has the same size as in the puzzle. The first kilobyte of bytes leaves a path
+/// to the exit, and the rest of them eventually block it with a wall across the memory space.
+/// `size` is raised if needed to fit that wall.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (side, fallen) = (MEMORY.size, MEMORY.fallen);
+    let (start, end) = ((0, 0), (side - 1, side - 1));
+    // With a fifth of the memory corrupted at random, the exit is hardly ever blocked
+    let mut free = loop {
+        let mut corrupted = Grid::new(side, side, false);
+        let mut free: Vec<_> = corrupted
+            .points()
+            .filter(|point| *point != start && *point != end)
+            .collect();
+        rng.shuffle(&mut free);
+        for byte in free[..fallen].iter() {
+            corrupted[*byte] = true;
+        }
+        if get_shortest_path(&corrupted, start, end).is_some() {
+            break free;
+        }
+    };
+    let mut bytes: Vec<_> = free.drain(..fallen).collect();
+    // Build a wall on a random column with the remaining bytes, and fill the rest at random
+    let column = rng.below(side as u64 - 2) as usize + 1;
+    let (mut wall, mut others): (Vec<_>, Vec<_>) =
+        free.into_iter().partition(|(x, _)| *x == column);
+    others.truncate(size.saturating_sub(fallen + wall.len()));
+    wall.append(&mut others);
+    rng.shuffle(&mut wall);
+    bytes.append(&mut wall);
+
+    let mut input = String::new();
+    for (x, y) in bytes {
+        input.push_str(&format!("{x},{y}\n"));
+    }
+    input
+}
@@ -0,0 +1,196 @@
+use common::{lines, Answer, ParseError, Solution};
+use grid::search::bfs;
+use grid::Grid;
+
+pub mod animate;
+pub mod generate;
+
+const DAY: u32 = 18;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+/// Size of the memory space, and number of bytes that fall before looking for the shortest path.
+#[derive(Debug, Clone, Copy)]
+pub struct Memory {
+    pub size: usize,
+    pub fallen: usize,
+}
+
+/// Memory space of the puzzle.
+pub const MEMORY: Memory = Memory {
+    size: 70 + 1,
+    fallen: 1024,
+};
+
+/// Memory space of the example in the puzzle description.
+pub const EXAMPLE_MEMORY: Memory = Memory {
+    size: 6 + 1,
+    fallen: 12,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_recorded_answers, read_example};
+
+    #[test]
+    fn test_example() {
+        let content = read_example(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, 1);
+        let bytes = parse(&content, EXAMPLE_MEMORY).unwrap();
+        assert_eq!(solve_part_one(&bytes, EXAMPLE_MEMORY), 22);
+        assert_eq!(solve_part_two(&bytes, EXAMPLE_MEMORY), Some((6, 1)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let memory = Memory { size: 7, fallen: 2 };
+        let error = parse("1,2\n3,7\n", memory).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.text, "7");
+        let error = parse("1,2\n", memory).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = parse("", memory).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(parse("1,2\n6,5\n", memory).unwrap(), [(1, 2), (6, 5)]);
+        // The exit is blocked by the bytes that fall first
+        let error = parse("1,0\n2,3\n0,1\n", Memory { size: 7, fallen: 3 }).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "0,1");
+    }
+
+    #[test]
+    fn test_part_two_no_blocking_byte() {
+        let memory = Memory { size: 3, fallen: 1 };
+        let bytes = parse("1,0\n1,1\n", memory).unwrap();
+        assert_eq!(solve_part_two(&bytes, memory), None);
+        let bytes = parse("1,0\n1,1\n1,2\n", memory).unwrap();
+        assert_eq!(solve_part_two(&bytes, memory), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day18>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+/// Parse the positions of the falling bytes.
+///
+/// They have to be inside the memory space, and at least as many as the ones that fall before
+/// looking for the shortest path. Those first bytes can't block the exit.
+pub fn parse(content: &str, memory: Memory) -> Result<Vec<(usize, usize)>, ParseError> {
+    let mut points = vec![];
+    let mut byte_lines = vec![];
+    for line in lines(DAY, content) {
+        let Some((x, y)) = line.text.split_once(",") else {
+            return Err(line.missing("',' separator"));
+        };
+        let mut point = [0, 0];
+        for (value, coordinate) in point.iter_mut().zip([x, y]) {
+            *value = line.parse::<usize>(coordinate)?;
+            if *value >= memory.size {
+                return Err(line.error(coordinate.trim(), "coordinate outside the memory space"));
+            }
+        }
+        points.push((point[0], point[1]));
+        byte_lines.push(line);
+    }
+    if points.len() < memory.fallen {
+        let message = format!("missing bytes, {} should fall first", memory.fallen);
+        return Err(ParseError::new(DAY, points.len() + 1, 1, "", &message));
+    }
+    if let Some(i) = find_blocking_byte(&points[..memory.fallen], memory.size) {
+        let line = byte_lines[i];
+        return Err(line.error(line.text, "byte blocks the exit before looking for a path"));
+    }
+    Ok(points)
+}
+
+/// Find the shortest path from start to end that avoids the corrupted positions
+fn get_shortest_path(
+    corrupted: &Grid<bool>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Option<Vec<(usize, usize)>> {
+    let search = bfs(
+        start,
+        |point| {
+            corrupted
+                .neighbors4(*point)
+                .filter(|neighbor| !corrupted[*neighbor])
+        },
+        |point| *point == end,
+    );
+    search.path()
+}
+
+fn get_minimum_distance(
+    corrupted: &Grid<bool>,
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Result<u32, &str> {
+    match get_shortest_path(corrupted, start, end) {
+        Some(path) => Ok(path.len() as u32 - 1),
+        None => Err("Couldn't find path to exit"),
+    }
+}
+
+/// Make the bytes fall one at a time, and return the index of the first one that blocks the exit.
+fn find_blocking_byte(bytes: &[(usize, usize)], size: usize) -> Option<usize> {
+    let mut corrupted = Grid::new(size, size, false);
+    let (start, end) = ((0, 0), (size - 1, size - 1));
+    // We only need to look for a new path when a byte falls on the current one
+    let mut path = get_shortest_path(&corrupted, start, end).unwrap_or_default();
+    for (i, byte) in bytes.iter().enumerate() {
+        corrupted[*byte] = true;
+        if !path.contains(byte) {
+            continue;
+        }
+        match get_shortest_path(&corrupted, start, end) {
+            Some(new_path) => path = new_path,
+            None => return Some(i),
+        }
+    }
+    None
+}
+
+pub fn solve_part_one(bytes: &[(usize, usize)], memory: Memory) -> u32 {
+    let mut corrupted = Grid::new(memory.size, memory.size, false);
+    let start = (0, 0);
+    let end = (memory.size - 1, memory.size - 1);
+    // Make the first kilobyte fall (mark those positions as corrupted)
+    for byte in bytes[0..memory.fallen].iter() {
+        corrupted[*byte] = true
+    }
+    // `parse` checks that those bytes leave a path to the exit
+    match get_minimum_distance(&corrupted, start, end) {
+        Ok(result) => result,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+pub fn solve_part_two(bytes: &[(usize, usize)], memory: Memory) -> Option<(usize, usize)> {
+    // The first bytes leave a path to the exit, as checked by `parse`
+    find_blocking_byte(bytes, memory.size).map(|i| bytes[i])
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input, MEMORY)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input, MEMORY).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        match solve_part_two(input, MEMORY) {
+            Some(byte) => byte.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use common::Rng;
+
+use crate::COLORS;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 100);
+        let (patterns, designs) = parse(&input).unwrap();
+        assert_eq!(designs.len(), 100);
+        let possible = solve_part_one(&patterns, &designs);
+        assert!(0 < possible && possible < 100);
+    }
+}
+
+const N_PATTERNS: usize = 400;
+
+fn random_stripes(rng: &mut Rng, colors: &[char], length: usize) -> String {
+    (0..length).map(|_| *rng.choose(colors)).collect()
+}
+
+/// Generate a puzzle input with `size` designs.
+///
+/// There are 400 towel patterns. One of the colors can only appear in the middle of a pattern
+/// and never twice in a row, so designs with two consecutive stripes of that color are impossible.
+/// Half of the designs are made of towels, and the rest get those two stripes near the start.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let colors: Vec<char> = COLORS.chars().collect();
+    let missing = *rng.choose(&colors);
+    let mut patterns = HashSet::new();
+    for color in colors.iter().filter(|c| **c != missing) {
+        patterns.insert(color.to_string());
+    }
+    let double = format!("{missing}{missing}");
+    while patterns.len() < N_PATTERNS {
+        let length = rng.range(2, 9) as usize;
+        let pattern = random_stripes(rng, &colors, length);
+        if !pattern.starts_with(missing)
+            && !pattern.ends_with(missing)
+            && !pattern.contains(&double)
+        {
+            patterns.insert(pattern);
+        }
+    }
+    let mut patterns: Vec<String> = patterns.into_iter().collect();
+    patterns.sort();
+    rng.shuffle(&mut patterns);
+
+    let mut input = patterns.join(", ");
+    input.push_str("\n\n");
+    for _ in 0..size {
+        let mut design = String::new();
+        while design.len() < 40 {
+            let pattern: &String = rng.choose(&patterns);
+            design.push_str(pattern);
+        }
+        if rng.chance(0.5) {
+            // The solver backtracks a lot before finding out, so keep them close to the start
+            let i = rng.below(10) as usize;
+            design.replace_range(i..i + 2, &double);
+        }
+        input.push_str(&design);
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,161 @@
+use common::{lines, parallel, Answer, Line, ParseError, Solution};
+use std::collections::HashSet;
+
+pub mod generate;
+
+const DAY: u32 = 19;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 1] = ["data/test_input"];
+const COLORS: &str = "wubrg";
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn test_part_one() {
+        let (patterns, designs) = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&patterns, &designs);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn test_parallel() {
+        let input = generate::generate(&mut common::Rng::new(1), 300);
+        let (patterns, designs) = parse(&input).unwrap();
+        let expected = solve_part_one(&patterns, &designs);
+        parallel::set_workers(4);
+        assert_eq!(solve_part_one(&patterns, &designs), expected);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day19>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["6"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day19>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Patterns {
+    patterns: HashSet<String>,
+    max_stripes: usize,
+}
+
+/// HasSet with the available patterns.
+impl Default for Patterns {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Patterns {
+    pub fn new() -> Self {
+        Patterns {
+            patterns: HashSet::new(),
+            max_stripes: 0,
+        }
+    }
+
+    pub fn new_from(patterns: Vec<String>) -> Self {
+        let mut new = Self::new();
+        for pattern in patterns {
+            new.add(pattern);
+        }
+        new
+    }
+
+    pub fn add(&mut self, pattern: String) {
+        let n_stripes = pattern.len();
+        if self.patterns.insert(pattern) && self.max_stripes < n_stripes {
+            self.max_stripes = n_stripes
+        }
+    }
+
+    /// Check if a given design is possible to be created with the available patterns.
+    pub fn is_possible(&self, design: &str) -> bool {
+        if design.is_empty() {
+            return true;
+        }
+        for n_colors in 1..=self.max_stripes {
+            // Don't check pattern if the design has less colors that current value of n_colors
+            if design.len() < n_colors {
+                continue;
+            }
+            // Check if there's a pattern we can use to start building the design. If so, run this
+            // recursively, checking if the rest of the design is also possible.
+            if self.patterns.contains(&design[0..n_colors]) && self.is_possible(&design[n_colors..])
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+// Check that a towel pattern or a design only has valid colors
+fn check_colors(line: &Line, stripes: &str) -> Result<(), ParseError> {
+    match stripes.chars().position(|c| !COLORS.contains(c)) {
+        Some(i) => Err(line.error_at(line.column_of(stripes) - 1 + i, "invalid color")),
+        None => Ok(()),
+    }
+}
+
+pub fn parse(content: &str) -> Result<(Patterns, Vec<String>), ParseError> {
+    let mut lines = lines(DAY, content);
+    let line = lines.expect("towel patterns")?;
+    let mut patterns: Vec<String> = vec![];
+    for pattern in line.text.split(",") {
+        check_colors(&line, pattern.trim())?;
+        patterns.push(pattern.trim().to_string());
+    }
+    let patterns = Patterns::new_from(patterns);
+    let mut designs: Vec<String> = vec![];
+    for line in lines.filter(|line| !line.text.is_empty()) {
+        check_colors(&line, line.text)?;
+        designs.push(line.text.to_string());
+    }
+    Ok((patterns, designs))
+}
+
+pub fn solve_part_one(patterns: &Patterns, designs: &[String]) -> u32 {
+    let n_possible_designs = parallel::map(designs, |d| patterns.is_possible(d))
+        .into_iter()
+        .filter(|is_possible| *is_possible)
+        .map(|b| b as u32)
+        .sum();
+    n_possible_designs
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Patterns, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (patterns, designs) = input;
+        solve_part_one(patterns, designs).into()
+    }
+}
@@ -0,0 +1,87 @@
+use common::Rng;
+use grid::search::bfs;
+use grid::{Direction, Grid, Point};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two, THRESHOLD};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 61);
+        let map = parse(&input).unwrap();
+        let (first, second) = (
+            solve_part_one(&map, THRESHOLD),
+            solve_part_two(&map, THRESHOLD),
+        );
+        assert!(0 < first && first < second);
+    }
+}
+
+/// Carve a random maze with a single path between any two cells at odd coordinates.
+fn carve_maze(rng: &mut Rng, size: usize) -> Grid<bool> {
+    let mut open = Grid::new(size, size, false);
+    let start = (1, 1);
+    open[start] = true;
+    let mut stack = vec![start];
+    while let Some(&current) = stack.last() {
+        let candidates: Vec<_> = Direction::CARDINAL
+            .iter()
+            .filter_map(|direction| {
+                let wall = open.next(current, *direction)?;
+                let cell = open.next(wall, *direction)?;
+                let inside = cell.0 < size - 1 && cell.1 < size - 1;
+                (inside && !open[cell]).then_some((wall, cell))
+            })
+            .collect();
+        if candidates.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (wall, cell) = *rng.choose(&candidates);
+        open[wall] = true;
+        open[cell] = true;
+        stack.push(cell);
+    }
+    open
+}
+
+/// Return the cell of the maze that is the farthest away from `start`.
+fn farthest(open: &Grid<bool>, start: Point) -> Point {
+    let search = bfs(
+        start,
+        |point| open.neighbors4(*point).filter(|n| open[*n]),
+        |_| false,
+    );
+    let (point, _) = search
+        .distances()
+        .iter()
+        .max_by_key(|(point, distance)| (**distance, **point))
+        .unwrap();
+    *point
+}
+
+/// Generate a square racetrack of `size` by `size` tiles, where `size` is rounded up to an odd
+/// number.
+///
+/// The track is the longest path of a random maze, so it's a single corridor that winds through
+/// the whole map with many walls that are worth cheating through.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let open = carve_maze(rng, size);
+    let start = farthest(&open, (1, 1));
+    let end = farthest(&open, start);
+    let search = bfs(
+        start,
+        |point| open.neighbors4(*point).filter(|n| open[*n]),
+        |point| *point == end,
+    );
+    let mut map = Grid::new(size, size, '#');
+    for point in search.path().unwrap() {
+        map[point] = '.';
+    }
+    map[start] = 'S';
+    map[end] = 'E';
+    map.to_string()
+}
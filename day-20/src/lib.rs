@@ -0,0 +1,214 @@
+use common::{parallel, Answer, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+
+type Times = Grid<Option<u32>>;
+pub type Map = Grid<char>;
+
+pub mod generate;
+
+const DAY: u32 = 20;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+/// Minimum time a cheat has to save to be counted in the puzzle.
+pub const THRESHOLD: u32 = 100;
+
+/// Minimum time a cheat has to save to be counted in the example of the puzzle description.
+pub const EXAMPLE_THRESHOLD: u32 = 50;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{check_recorded_answers, read_example};
+
+    #[test]
+    fn test_example() {
+        let content = read_example(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, 1);
+        let map = parse(&content).unwrap();
+        assert_eq!(solve_part_one(&map, EXAMPLE_THRESHOLD), 1);
+        assert_eq!(solve_part_two(&map, EXAMPLE_THRESHOLD), 285);
+    }
+
+    #[test]
+    fn test_parse_branching_track() {
+        let error = parse("#####\n#S.E#\n#.###\n#####\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "S"));
+        assert_eq!(error.message, "the track branches");
+        let error = parse("#####\n#S#E#\n#####\n").unwrap_err();
+        assert_eq!(error.message, "the track ends before reaching 'E'");
+        let error = parse("######\n#S.E.#\n######\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 5, "."));
+        assert_eq!(error.message, "tile outside the track");
+        assert!(parse("#####\n#S.E#\n#####\n").is_ok());
+    }
+
+    #[test]
+    fn test_parallel() {
+        let map = parse(&generate::generate(&mut common::Rng::new(1), 41)).unwrap();
+        let expected = (solve_part_one(&map, 10), solve_part_two(&map, 10));
+        parallel::set_workers(4);
+        let result = (solve_part_one(&map, 10), solve_part_two(&map, 10));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day20>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+fn get_points_at_distance<T>(grid: &Grid<T>, point: &Point, distance: u32) -> Vec<Point> {
+    let distance = distance as i64;
+    let mut deltas: Vec<(i64, i64)> = vec![];
+    for direction in Direction::CARDINAL {
+        let (dx, dy) = direction.delta();
+        deltas.push((dx * distance, dy * distance));
+    }
+    for direction in Direction::ORDINAL {
+        let (sign_x, sign_y) = direction.delta();
+        for d in 1..distance {
+            deltas.push((sign_x * (distance - d), sign_y * d));
+        }
+    }
+    let points = deltas
+        .iter()
+        .filter_map(|(dx, dy)| grid.step(*point, *dx, *dy))
+        .collect();
+    points
+}
+
+/// Return a vec with the location of points of the path (in no order)
+fn get_path(map: &Map) -> Vec<Point> {
+    let path = map
+        .iter()
+        .filter(|(_, element)| **element != '#')
+        .map(|(point, _)| point)
+        .collect();
+    path
+}
+
+/// Calculate the time it takes to reach each point of the path.
+///
+/// Return a `Grid<Option<32>>` with the time it takes to reach each one of the points in the
+/// map. If the point is a wall, then its time will be `None`. If the path branches or ends before
+/// reaching E, return that point along with the reason.
+fn get_times(map: &Map) -> Result<Times, (Point, &'static str)> {
+    let mut times = Grid::new(map.width(), map.height(), None);
+    let mut visited = Grid::new(map.width(), map.height(), false);
+
+    // Mark S as the current point and assign a time zero to it
+    let mut time = 0;
+    let mut current = map.find(&'S').unwrap();
+    times[current] = Some(time);
+
+    let end = map.find(&'E').unwrap();
+    while current != end {
+        // Mark current point as visited
+        visited[current] = true;
+        // Look for the next point in the path
+        let path_neighbors: Vec<Point> = map
+            .neighbors4(current)
+            .filter(|n| map[*n] != '#')
+            .filter(|n| !visited[*n])
+            .collect();
+        match path_neighbors.len() {
+            0 => return Err((current, "the track ends before reaching 'E'")),
+            1 => (),
+            _ => return Err((current, "the track branches")),
+        };
+        // Update current point and write down the time it takes to get to it
+        current = path_neighbors[0];
+        time += 1;
+        times[current] = Some(time);
+    }
+    Ok(times)
+}
+
+fn count_cheats(map: &Map, times: &Times, threshold: u32, max_cheat_time: u32) -> u32 {
+    let path = get_path(map);
+    let n_cheats = parallel::map(&path, |point| {
+        count_cheats_from(*point, map, times, threshold, max_cheat_time)
+    });
+    n_cheats.iter().sum()
+}
+
+fn count_cheats_from(
+    point: Point,
+    map: &Map,
+    times: &Times,
+    threshold: u32,
+    max_cheat_time: u32,
+) -> u32 {
+    let mut n_cheats = 0;
+    let time = times[point].unwrap();
+    for distance in 2..=max_cheat_time {
+        let equidistants = get_points_at_distance(map, &point, distance);
+        for other in equidistants.iter() {
+            if let Some(other_time) = times[*other] {
+                let saved_time = other_time as i32 - (time + distance) as i32;
+                if saved_time >= threshold as i32 {
+                    n_cheats += 1;
+                }
+            }
+        }
+    }
+    n_cheats
+}
+
+pub fn parse(content: &str) -> Result<Map, ParseError> {
+    let map = Grid::parse_with(DAY, content, |c| "#.SE".contains(c).then_some(c))?;
+    for tile in ['S', 'E'] {
+        if map.find(&tile).is_none() {
+            let message = format!("couldn't find the '{tile}' tile");
+            return Err(ParseError::new(DAY, 1, 1, "", &message));
+        }
+    }
+    // The race track has to be a single path from S to E
+    let error_at = |(x, y): Point, message: &str| {
+        ParseError::new(DAY, y + 1, x + 1, &map[(x, y)].to_string(), message)
+    };
+    let times = get_times(&map).map_err(|(point, message)| error_at(point, message))?;
+    if let Some((point, _)) = map
+        .iter()
+        .find(|(point, tile)| **tile != '#' && times[*point].is_none())
+    {
+        return Err(error_at(point, "tile outside the track"));
+    }
+    Ok(map)
+}
+
+pub fn solve_part_one(map: &Map, threshold: u32) -> u32 {
+    count_shortcuts(map, threshold, 2)
+}
+
+pub fn solve_part_two(map: &Map, threshold: u32) -> u32 {
+    count_shortcuts(map, threshold, 20)
+}
+
+fn count_shortcuts(map: &Map, threshold: u32, max_cheat_time: u32) -> u32 {
+    let times = match get_times(map) {
+        Ok(times) => times,
+        Err((point, message)) => panic!("Invalid track at {point:?}: {message}"),
+    };
+    let n_cheats = count_cheats(map, &times, threshold, max_cheat_time);
+    n_cheats
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input, THRESHOLD).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input, THRESHOLD).into()
+    }
+}
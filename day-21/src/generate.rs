@@ -0,0 +1,24 @@
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 20);
+        let codes = parse(&input).unwrap();
+        assert_eq!(codes.len(), 20);
+        assert!(solve_part_one(&codes) < solve_part_two(&codes));
+    }
+}
+
+/// Generate a puzzle input with `size` door codes.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        input.push_str(&format!("{:03}A\n", rng.range(1, 1000)));
+    }
+    input
+}
@@ -0,0 +1,299 @@
+use common::{lines, Answer, ParseError, Solution};
+use itertools::{repeat_n, Itertools};
+use std::collections::HashMap;
+
+pub mod generate;
+
+const DAY: u32 = 21;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn test_part_one() {
+        let codes = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&codes);
+        assert_eq!(result, 126384)
+    }
+
+    #[test]
+    fn test_cache() {
+        // Sharing the cache between codes gives the same lengths as starting from an empty one
+        let mut cache = Cache::new();
+        for (code, expected) in [("029A", 68), ("980A", 60), ("179A", 68)] {
+            let cached = get_shortest_length(code.to_string(), 3, &mut cache);
+            let uncached = get_shortest_length(code.to_string(), 3, &mut Cache::new());
+            assert_eq!((cached, uncached), (expected, expected));
+        }
+        assert_eq!(cache.get(&("029A".to_string(), 3)), Some(&68));
+        // Lengths are cached for each number of keypads
+        let (one, two) = (("<A".to_string(), 1), ("<A".to_string(), 2));
+        assert!(cache[&one] < cache[&two]);
+        cache.insert(one.clone(), 1000);
+        assert_eq!(get_shortest_length(one.0, one.1, &mut cache), 1000);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day21>(
+            env!("CARGO_MANIFEST_DIR"),
+            &EXAMPLES,
+            &["126384", "154115708116294"],
+        );
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day21>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+type Position = (i32, i32);
+type Cache = HashMap<(String, u32), u64>;
+
+#[derive(Debug)]
+enum Keypad {
+    Directional,
+    Numeric,
+}
+
+impl Keypad {
+    fn new_from(code: &str) -> Self {
+        let is_numeric = {
+            let mut is_numeric = true;
+            for c in code.chars() {
+                if c != 'A' && !c.is_numeric() {
+                    is_numeric = false;
+                    break;
+                }
+            }
+            is_numeric
+        };
+        match is_numeric {
+            true => Keypad::Numeric,
+            false => Keypad::Directional,
+        }
+    }
+
+    /// Get all possible sequences to get from the start key to the end key.
+    ///
+    /// These sequences are all valid (visit only valid keys) and all are the shortest paths to get
+    /// from the start key to the end key.
+    fn get_partial_sequences(&self, start_key: char, end_key: char) -> Vec<String> {
+        // Get start and end positions
+        let start = self.get_position(start_key);
+        let end = self.get_position(end_key);
+
+        // Find all possible sequences
+        let nx = end.0 - start.0;
+        let ny = end.1 - start.1;
+        let base_sequences = fill_direction_chars(nx, true) + &fill_direction_chars(ny, false);
+        let permutations = base_sequences
+            .chars()
+            .permutations(base_sequences.len())
+            .unique();
+        let mut sequences: Vec<String> = permutations
+            .map(|p| p.iter().collect::<String>())
+            .filter(|sequences| self.is_valid(start_key, sequences))
+            .collect();
+        for sequence in sequences.iter_mut() {
+            sequence.push('A')
+        }
+        sequences
+    }
+
+    /// Check whether a sequence is valid for this keypad.
+    fn is_valid(&self, start: char, sequences: &str) -> bool {
+        let forbidden = match self {
+            Keypad::Numeric => (0, 3),
+            Keypad::Directional => (0, 0),
+        };
+        let mut current_position = self.get_position(start);
+        for sequence in sequences.chars() {
+            current_position = walk(&current_position, sequence);
+            if current_position == forbidden {
+                return false;
+            };
+        }
+        true
+    }
+
+    fn get_position(&self, key: char) -> Position {
+        match self {
+            Keypad::Numeric => match key {
+                '0' => (1, 3),
+                'A' => (2, 3),
+                '1' => (0, 2),
+                '2' => (1, 2),
+                '3' => (2, 2),
+                '4' => (0, 1),
+                '5' => (1, 1),
+                '6' => (2, 1),
+                '7' => (0, 0),
+                '8' => (1, 0),
+                '9' => (2, 0),
+                _ => panic!("Invalid key {key} for a numeric pad"),
+            },
+            Keypad::Directional => match key {
+                '^' => (1, 0),
+                'A' => (2, 0),
+                'v' => (1, 1),
+                '<' => (0, 1),
+                '>' => (2, 1),
+                _ => panic!("Invalid key {key} for a directional pad"),
+            },
+        }
+    }
+}
+
+/// Generate a String full of one of the direction characters.
+fn fill_direction_chars(displacement: i32, horizontal: bool) -> String {
+    let direction = match horizontal {
+        true => match displacement.signum() {
+            -1 => '<',
+            1 => '>',
+            0 => '@',
+            _ => panic!(),
+        },
+        false => match displacement.signum() {
+            -1 => '^',
+            1 => 'v',
+            0 => '@',
+            _ => panic!(),
+        },
+    };
+    repeat_n(direction, displacement.unsigned_abs() as usize).collect::<String>()
+}
+
+fn walk(position: &Position, sequence: char) -> Position {
+    match sequence {
+        '>' => (position.0 + 1, position.1),
+        '<' => (position.0 - 1, position.1),
+        '^' => (position.0, position.1 - 1),
+        'v' => (position.0, position.1 + 1),
+        e => panic!("Invalid sequence: {e}"),
+    }
+}
+
+/// Prepend a string with a single character.
+fn prepend(code: &str, leading: char) -> String {
+    format!("{leading}") + code
+}
+
+/// Get the shortest sequence we need to input in the directional keypad to produce the desired
+/// code.
+///
+/// The `n_keypads` is the total number of keypads operated by a robot, including the numeric one.
+/// So, if we have Keypad -> Keypad -> Keypad -> Numpad, where we can type our sequence in the
+/// first one, we have a total of 3 keypads controlled by a robot.
+///
+/// We memoize this function to significatnly speed up the computation: since there will be a lot
+/// of repeated sequences that will be passed to this function in the recursion, it's best to cache
+/// the results rather than repeating the computation (which is costly specially when `n_keypads`
+/// is a high number). The results are stored in the `cache`, indexed by `(code, n_keypads)`.
+fn get_shortest_length(code: String, n_keypads: u32, cache: &mut Cache) -> u64 {
+    if let Some(length) = cache.get(&(code.clone(), n_keypads)) {
+        return *length;
+    }
+    // Generate the keypad based on the code: the code will be numeric, so the first keypad should
+    // be also numeric.
+    let keypad = Keypad::new_from(&code);
+    if n_keypads == 0 {
+        return code.len() as u64;
+    }
+    // Prepend an A to the code: all robots start a code in the A key.
+    let key = (code.clone(), n_keypads);
+    let code = prepend(&code, 'A');
+    // Find shortest length recursively, iterating over pair of chars (moving windows).
+    let mut shortest_length = 0;
+    for (start, end) in code.chars().tuple_windows() {
+        // Get all possible sequences to generate that code
+        let sequences = keypad.get_partial_sequences(start, end);
+        // Get the minimum length of the sequence at the last keymap (recursively)
+        let min_length = sequences
+            .iter()
+            .map(|sequence| get_shortest_length(sequence.to_string(), n_keypads - 1, cache))
+            .min()
+            .unwrap();
+        // Add the minimum length to the running result
+        shortest_length += min_length;
+    }
+    cache.insert(key, shortest_length);
+    shortest_length
+}
+
+/// Compute the complexity of a given code.
+/// The complexity of a code its defined as the product of the lenght of the shortest sequence and
+/// the numerical part of the code.
+fn get_complexity(code: &str, n_keypads: u32, cache: &mut Cache) -> u64 {
+    let min_length = get_shortest_length(code.to_string(), n_keypads, cache);
+    let numeric_part: u64 = code
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect::<String>()
+        .parse()
+        .unwrap();
+    numeric_part * min_length
+}
+
+pub fn parse(content: &str) -> Result<Vec<String>, ParseError> {
+    let mut codes = vec![];
+    for line in lines(DAY, content) {
+        if let Some(i) = line.text.chars().position(|c| !"0123456789A".contains(c)) {
+            return Err(line.error_at(i, "invalid key"));
+        }
+        if line.text.len() != 4 || !line.text.ends_with("A") {
+            return Err(line.error(line.text, "expected three digits followed by 'A'"));
+        }
+        codes.push(line.text.to_string());
+    }
+    Ok(codes)
+}
+
+fn sum_complexities(codes: &[String], n_keypads: u32) -> u64 {
+    let mut cache = Cache::new();
+    let complexities = codes
+        .iter()
+        .map(|code| get_complexity(code, n_keypads, &mut cache))
+        .sum();
+    complexities
+}
+
+pub fn solve_part_one(codes: &[String]) -> u64 {
+    sum_complexities(codes, 3)
+}
+
+pub fn solve_part_two(codes: &[String]) -> u64 {
+    sum_complexities(codes, 26)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
@@ -0,0 +1,27 @@
+use common::Rng;
+
+use crate::PRUNER;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 100);
+        let secret_numbers = parse(&input).unwrap();
+        assert_eq!(secret_numbers.len(), 100);
+        assert!(secret_numbers.iter().all(|n| *n < PRUNER));
+        assert!(solve_part_one(&secret_numbers) > 0);
+    }
+}
+
+/// Generate a puzzle input with the initial secret numbers of `size` buyers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        input.push_str(&format!("{}\n", rng.below(PRUNER)));
+    }
+    input
+}
@@ -0,0 +1,92 @@
+use common::{lines, parallel, Answer, ParseError, Solution};
+
+pub mod generate;
+
+const DAY: u32 = 22;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 1] = ["data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+1
+10
+100
+2024
+";
+
+    #[test]
+    fn test_part_one() {
+        let initial_secret_numbers = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&initial_secret_numbers);
+        assert_eq!(result, 37327623);
+    }
+
+    #[test]
+    fn test_parallel() {
+        let numbers = parse(&generate::generate(&mut common::Rng::new(1), 500)).unwrap();
+        let expected = solve_part_one(&numbers);
+        parallel::set_workers(4);
+        assert_eq!(solve_part_one(&numbers), expected);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day22>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["37327623"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day22>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+const PRUNER: u64 = 16777216; // this is eq to 0b100000... (with 24 zeros)
+
+fn next_secret_number(secret_number: u64) -> u64 {
+    let mut next_secret_number = ((secret_number * 64) ^ secret_number) % PRUNER;
+    next_secret_number = ((next_secret_number / 32) ^ next_secret_number) % PRUNER;
+    next_secret_number = ((next_secret_number * 2048) ^ next_secret_number) % PRUNER;
+    next_secret_number
+}
+
+fn predict_nth_secret_number(secret_number: u64, nth: usize) -> u64 {
+    let mut secret_number = secret_number;
+    for _ in 0..nth {
+        secret_number = next_secret_number(secret_number);
+    }
+    secret_number
+}
+
+pub fn parse(content: &str) -> Result<Vec<u64>, ParseError> {
+    let initial_secret_numbers = lines(DAY, content)
+        .map(|line| line.parse::<u64>(line.text))
+        .collect();
+    initial_secret_numbers
+}
+
+pub fn solve_part_one(initial_secret_numbers: &[u64]) -> u64 {
+    parallel::map(initial_secret_numbers, |x| {
+        predict_nth_secret_number(*x, 2_000)
+    })
+    .iter()
+    .sum()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+}
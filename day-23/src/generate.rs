@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, solve_part_two};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 200);
+        let network = parse(&input).unwrap();
+        assert!(solve_part_one(&network) > 0);
+        assert_eq!(solve_part_two(&network).split(",").count(), 13);
+    }
+}
+
+const CLIQUE_SIZE: usize = 13;
+const CONNECTIONS: usize = 13;
+
+/// Generate a network of `size` computers, up to the 676 available two-letter names.
+///
+/// Every computer is randomly connected to about 13 others, and one group of 13 computers is
+/// fully connected to be the LAN party.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let letters = "abcdefghijklmnopqrstuvwxyz";
+    let mut names: Vec<String> = letters
+        .chars()
+        .flat_map(|a| letters.chars().map(move |b| format!("{a}{b}")))
+        .collect();
+    rng.shuffle(&mut names);
+    let size = size.min(names.len());
+    names.truncate(size);
+
+    let mut connections = HashSet::new();
+    let party = CLIQUE_SIZE.min(size);
+    for i in 0..party {
+        for j in i + 1..party {
+            connections.insert((i, j));
+        }
+    }
+    for i in party..size {
+        for _ in 0..CONNECTIONS / 2 {
+            let j = rng.below(size as u64) as usize;
+            if i != j {
+                connections.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+    let mut connections: Vec<_> = connections.into_iter().collect();
+    connections.sort();
+    rng.shuffle(&mut connections);
+
+    let mut input = String::new();
+    for (i, j) in connections {
+        let (a, b) = match rng.chance(0.5) {
+            true => (&names[i], &names[j]),
+            false => (&names[j], &names[i]),
+        };
+        input.push_str(&format!("{a}-{b}\n"));
+    }
+    input
+}
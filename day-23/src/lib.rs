@@ -0,0 +1,256 @@
+use common::{lines, Answer, ParseError, Solution};
+use std::collections::BTreeMap;
+
+pub mod generate;
+
+const DAY: u32 = 23;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 2] = ["data/test_input", "data/test_input"];
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn test_part_one() {
+        let network = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&network);
+        assert_eq!(result, 7);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let network = parse(EXAMPLE).unwrap();
+        let result = solve_part_two(&network);
+        assert_eq!(result, "co,de,ka,ta");
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day23>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["7", "co,de,ka,ta"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day23>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+type Set = Vec<String>;
+
+pub struct Network {
+    net: BTreeMap<String, Set>,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Network {
+    pub fn new() -> Self {
+        let net = BTreeMap::new();
+        Self { net }
+    }
+
+    pub fn insert(&mut self, a: &str, b: &str) {
+        // Sort the two computers alphabetically
+        let mut pair = [a, b];
+        pair.sort();
+        let [a, b] = pair;
+        self.net
+            .entry(a.to_string())
+            .and_modify(|set| set.push(b.to_string()))
+            .or_insert(vec![b.to_string()]);
+    }
+
+    pub fn get(&self, a: &str) -> Option<&Set> {
+        self.net.get(a)
+    }
+
+    fn are_connected(&self, a: &str, b: &str) -> bool {
+        // Sort the two computers alphabetically
+        let mut pair = [a, b];
+        pair.sort();
+        let [a, b] = pair;
+        let connections = match self.get(a) {
+            None => return false,
+            Some(c) => c,
+        };
+        connections.contains(&b.to_string())
+    }
+
+    /// Get the largest subnet in the network
+    pub fn get_largest_subnet(&self) -> Set {
+        let mut largest_subnet = vec![];
+        for computer in self.net.keys() {
+            let subnets = match self.get_largest_subnets(computer) {
+                None => continue,
+                Some(s) => s,
+            };
+            let subnet = subnets[0].clone();
+            if subnet.len() > largest_subnet.len() {
+                largest_subnet = subnet;
+            }
+        }
+        largest_subnet
+    }
+
+    /// Get the largest subnets that a computer belongs to
+    fn get_largest_subnets(&self, computer: &str) -> Option<Vec<Set>> {
+        let connections = match self.get(computer) {
+            None => panic!(),
+            Some(c) => c,
+        };
+        let subnets = self.get_subnets_recursively(computer, connections)?;
+        let max_length = subnets.iter().map(|s| s.len()).max().unwrap();
+        let largest_subnets = subnets
+            .into_iter()
+            .filter(|s| s.len() == max_length)
+            .collect();
+        Some(largest_subnets)
+    }
+
+    /// Recursive function to get the subnets of a given computer.
+    /// The targets argument is a Set of computers to which we are going to verify if the given
+    /// computer is connected to.
+    fn get_subnets_recursively(&self, computer: &str, targets: &Set) -> Option<Vec<Set>> {
+        let connections = match self.get(computer) {
+            Some(c) => c,
+            None => {
+                if targets.contains(&computer.to_string()) {
+                    let set = vec![computer.to_string()];
+                    return Some(vec![set]);
+                };
+                return None;
+            }
+        };
+        // Keep only the connections that are also in targets
+        let connections: Vec<String> = connections
+            .iter()
+            .filter(|c| targets.contains(&c.to_string()))
+            .map(|c| c.to_string())
+            .collect();
+        if connections.is_empty() {
+            return None;
+        }
+
+        let mut subnets = vec![];
+        for connection in connections.iter() {
+            let mut connections_subnets =
+                match self.get_subnets_recursively(connection, &connections) {
+                    Some(s) => s,
+                    None => continue,
+                };
+            for s in connections_subnets.iter_mut() {
+                s.push(computer.to_string());
+            }
+            subnets.extend(connections_subnets);
+        }
+        if subnets.is_empty() {
+            return None;
+        }
+        Some(subnets)
+    }
+}
+
+/// Count the number of subnets of length 3 that includes a computer that starts with 't'.
+fn count_subnets_len_3_with_t(network: &Network) -> u32 {
+    let mut n_subnets = 0;
+    for (computer, connections) in network.net.iter() {
+        for (i, computer_a) in connections.iter().enumerate() {
+            for computer_b in connections[i + 1..].iter() {
+                let any_with_t = computer.starts_with('t')
+                    || computer_a.starts_with('t')
+                    || computer_b.starts_with('t');
+                if any_with_t && network.are_connected(computer_a, computer_b) {
+                    n_subnets += 1;
+                }
+            }
+        }
+    }
+    n_subnets
+}
+
+pub fn parse(content: &str) -> Result<Network, ParseError> {
+    let mut network = Network::new();
+    for line in lines(DAY, content) {
+        let (a, b) = match line.text.split_once("-") {
+            Some(computers) => computers,
+            None => return Err(line.missing("'-' separator")),
+        };
+        for computer in [a, b] {
+            if computer.len() != 2 || !computer.chars().all(|c| c.is_ascii_lowercase()) {
+                return Err(line.error(computer, "invalid computer name"));
+            }
+        }
+        network.insert(a, b);
+    }
+    Ok(network)
+}
+
+pub fn solve_part_one(network: &Network) -> u32 {
+    count_subnets_len_3_with_t(network)
+}
+
+pub fn solve_part_two(network: &Network) -> String {
+    let mut largest_subnet = network.get_largest_subnet();
+    largest_subnet.sort();
+    largest_subnet.join(",")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Network;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        solve_part_two(input).into()
+    }
+}
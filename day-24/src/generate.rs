@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use common::Rng;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one, Stack};
+
+    fn read_number(stack: &Stack, prefix: &str) -> u64 {
+        let number = stack
+            .iter()
+            .filter(|(wire, value)| wire.starts_with(prefix) && **value)
+            .map(|(wire, _)| 1 << wire[1..].parse::<u64>().unwrap())
+            .sum();
+        number
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 45);
+        let (rules, stack) = parse(&input).unwrap();
+        assert_eq!(stack.len(), 90);
+        let (x, y) = (read_number(&stack, "x"), read_number(&stack, "y"));
+        assert_eq!(solve_part_one(&rules, &stack), x + y);
+    }
+}
+
+/// Return a new random name for a wire, which can't be confused with the inputs or outputs.
+fn new_wire(rng: &mut Rng, used: &mut HashSet<String>) -> String {
+    let letters: Vec<char> = "abcdefghijklmnopqrstuvw".chars().collect();
+    loop {
+        let wire: String = (0..3).map(|_| *rng.choose(&letters)).collect();
+        if used.insert(wire.clone()) {
+            return wire;
+        }
+    }
+}
+
+/// Generate a system of gates that adds two numbers of `size` bits, with random initial values.
+///
+/// The gates make a ripple-carry adder, with the same structure as the ones of the puzzle but
+/// without any swapped wire. `size` can't be larger than 63, so the result fits in 64 bits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 63);
+    let mut input = String::new();
+    for prefix in ["x", "y"] {
+        for i in 0..size {
+            input.push_str(&format!("{prefix}{i:02}: {}\n", rng.below(2)));
+        }
+    }
+    input.push('\n');
+
+    let mut used = HashSet::new();
+    let mut gates = vec![];
+    let mut carry = String::new();
+    for i in 0..size {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        if i == 0 {
+            carry = new_wire(rng, &mut used);
+            gates.push((x.clone(), "XOR", y.clone(), z));
+            gates.push((x, "AND", y, carry.clone()));
+            continue;
+        }
+        let sum = new_wire(rng, &mut used);
+        let both = new_wire(rng, &mut used);
+        let carried = new_wire(rng, &mut used);
+        let next_carry = match i == size - 1 {
+            true => format!("z{size:02}"),
+            false => new_wire(rng, &mut used),
+        };
+        gates.push((x.clone(), "XOR", y.clone(), sum.clone()));
+        gates.push((sum.clone(), "XOR", carry.clone(), z));
+        gates.push((x, "AND", y, both.clone()));
+        gates.push((sum, "AND", carry, carried.clone()));
+        gates.push((both, "OR", carried, next_carry.clone()));
+        carry = next_carry;
+    }
+    if size == 1 {
+        // A single bit adder has no OR gate writing the last output
+        gates.push((carry.clone(), "OR", carry, "z01".to_string()));
+    }
+    rng.shuffle(&mut gates);
+    for (a, gate, b, output) in gates {
+        let (a, b) = match rng.chance(0.5) {
+            true => (a, b),
+            false => (b, a),
+        };
+        input.push_str(&format!("{a} {gate} {b} -> {output}\n"));
+    }
+    input
+}
@@ -0,0 +1,331 @@
+use common::{lines, Answer, Line, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt;
+
+pub mod generate;
+
+const DAY: u32 = 24;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 1] = ["data/test_input_02"];
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE_01: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    const EXAMPLE_02: &str = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+
+    #[test]
+    fn test_part_one_01() {
+        let (rules, stack) = parse(EXAMPLE_01).unwrap();
+        let result = solve_part_one(&rules, &stack);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part_one_02() {
+        let (rules, stack) = parse(EXAMPLE_02).unwrap();
+        let result = solve_part_one(&rules, &stack);
+        assert_eq!(result, 2024);
+    }
+
+    #[test]
+    fn test_parse_invalid_logic_gate() {
+        let error = parse("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00\n").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (24, 4, 5));
+        assert_eq!(error.text, "NAND");
+    }
+
+    #[test]
+    fn test_parse_invalid_wires() {
+        let error = parse("x00: 1\ny00: 0\n\nx00 AND y00 -> z00\nz00 OR w00 -> z01\n").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 8));
+        assert_eq!(error.message, "undefined wire");
+        let cycle = "x00: 1\n\nx00 AND b -> a\na OR x00 -> b\nb XOR x00 -> z00\n";
+        let error = parse(cycle).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(
+            (error.text.as_str(), error.message.as_str()),
+            ("a", "wire depends on itself")
+        );
+        let error = parse("x00: 1\n\nz00 OR x00 -> z00\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day24>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["2024"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day24>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+pub type Rules = HashMap<String, (LogicGate, String, String)>;
+pub type Stack = HashMap<String, bool>;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+pub enum LogicGate {
+    AND,
+    OR,
+    XOR,
+}
+
+impl fmt::Display for LogicGate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = match self {
+            LogicGate::AND => "AND",
+            LogicGate::OR => "OR",
+            LogicGate::XOR => "XOR",
+        };
+        write!(f, "{}", string)
+    }
+}
+
+impl LogicGate {
+    fn new_from(string: &str) -> Option<Self> {
+        match string {
+            "AND" => Some(LogicGate::AND),
+            "OR" => Some(LogicGate::OR),
+            "XOR" => Some(LogicGate::XOR),
+            _ => None,
+        }
+    }
+
+    fn get_output(&self, a: bool, b: bool) -> bool {
+        match self {
+            LogicGate::AND => a & b,
+            LogicGate::OR => a | b,
+            LogicGate::XOR => a ^ b,
+        }
+    }
+}
+
+fn parse_bool(string: &str) -> Option<bool> {
+    match string {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None,
+    }
+}
+
+fn bools_to_int(bools: Vec<bool>) -> u64 {
+    let mut result = 0;
+    for (i, bool) in bools.iter().enumerate() {
+        if *bool {
+            result += 2u64.pow(i as u32)
+        }
+    }
+    result
+}
+
+/// A logic gate as written in the input: its line, output wire and input wires.
+type Gate<'a> = (Line<'a>, &'a str, [&'a str; 2]);
+
+/// Progress of the search for cycles on a gate.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    Started,
+    Done,
+}
+
+/// Visit the gates that a gate depends on, returning an error if one of them depends on it.
+fn check_cycles(
+    gate: usize,
+    gates: &[Gate],
+    outputs: &HashMap<&str, usize>,
+    visits: &mut [Visit],
+) -> Result<(), ParseError> {
+    visits[gate] = Visit::Started;
+    let (line, _, inputs) = &gates[gate];
+    for input in inputs.iter() {
+        // Wires with an initial value don't depend on anything
+        let Some(&other) = outputs.get(input) else {
+            continue;
+        };
+        match visits[other] {
+            Visit::New => check_cycles(other, gates, outputs, visits)?,
+            Visit::Started => return Err(line.error(input, "wire depends on itself")),
+            Visit::Done => (),
+        }
+    }
+    visits[gate] = Visit::Done;
+    Ok(())
+}
+
+/// Check that every input of the gates has an initial value or is the output of a gate, and that
+/// no wire depends on itself.
+fn check_wires(gates: &[Gate], stack: &Stack) -> Result<(), ParseError> {
+    let outputs: HashMap<&str, usize> = gates
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, output, _))| !stack.contains_key(*output))
+        .map(|(i, (_, output, _))| (*output, i))
+        .collect();
+    for (line, _, inputs) in gates.iter() {
+        for input in inputs.iter() {
+            if !stack.contains_key(*input) && !outputs.contains_key(input) {
+                return Err(line.error(input, "undefined wire"));
+            }
+        }
+    }
+    let mut visits = vec![Visit::New; gates.len()];
+    for gate in 0..gates.len() {
+        if visits[gate] == Visit::New {
+            check_cycles(gate, gates, &outputs, &mut visits)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn parse(content: &str) -> Result<(Rules, Stack), ParseError> {
+    let mut rules = Rules::new();
+    let mut stack = Stack::new();
+    let mut lines = lines(DAY, content);
+    loop {
+        let line = lines.expect("logic gates")?;
+        if line.text.is_empty() {
+            break;
+        };
+        let (variable, value) = match line.text.split_once(":") {
+            Some(parts) => parts,
+            None => return Err(line.missing("':' separator")),
+        };
+        let value = match parse_bool(value.trim()) {
+            Some(value) => value,
+            None => return Err(line.error(value.trim(), "expected 0 or 1")),
+        };
+        stack.insert(variable.trim().to_string(), value);
+    }
+    let mut gates = vec![];
+    for line in lines {
+        let (operation, output) = match line.text.split_once("->") {
+            Some(parts) => parts,
+            None => return Err(line.missing("'->' separator")),
+        };
+        let operation: Vec<&str> = operation.split_whitespace().collect();
+        if operation.len() != 3 {
+            return Err(line.error(line.text, "expected an operation like 'x00 AND y00'"));
+        }
+        let logic_gate = match LogicGate::new_from(operation[1]) {
+            Some(logic_gate) => logic_gate,
+            None => return Err(line.error(operation[1], "invalid logic gate")),
+        };
+        let (arg1, arg2) = (operation[0].to_string(), operation[2].to_string());
+        rules.insert(output.trim().to_string(), (logic_gate, arg1, arg2));
+        gates.push((line, output.trim(), [operation[0], operation[2]]));
+    }
+    check_wires(&gates, &stack)?;
+    Ok((rules, stack))
+}
+
+fn get_value(variable: &str, rules: &Rules, stack: &Stack) -> bool {
+    if stack.contains_key(variable) {
+        return stack.get(variable).unwrap().to_owned();
+    }
+    let (logic_gate, arg1, arg2) = rules.get(variable).unwrap();
+    let arg1_value = get_value(arg1, rules, stack);
+    let arg2_value = get_value(arg2, rules, stack);
+    let output = logic_gate.get_output(arg1_value, arg2_value);
+    output
+}
+
+pub fn solve_part_one(rules: &Rules, stack: &Stack) -> u64 {
+    let zetas = {
+        let mut zetas: Vec<String> = rules
+            .keys()
+            .filter(|k| k.starts_with("z"))
+            .map(|k| k.to_string())
+            .collect();
+        zetas.sort();
+        // zetas.reverse();
+        zetas
+    };
+    let mut zetas_values: Vec<bool> = vec![];
+    for zeta in zetas.iter() {
+        let value = get_value(zeta, rules, stack);
+        zetas_values.push(value);
+    }
+    bools_to_int(zetas_values)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = (Rules, Stack);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        let (rules, stack) = input;
+        solve_part_one(rules, stack).into()
+    }
+}
@@ -0,0 +1,43 @@
+use common::Rng;
+
+use crate::{COMBINATION_LENGTH, TUMBLER_HEIGHT};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, solve_part_one};
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut Rng::new(1), 100);
+        let tumblers = parse(&input).unwrap();
+        assert_eq!(tumblers.len(), 100);
+        assert!(solve_part_one(&tumblers) > 0);
+    }
+}
+
+/// Generate the schematics of `size` locks and keys, with about as many of each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let height = TUMBLER_HEIGHT as usize;
+    let mut schematics = vec![];
+    for _ in 0..size {
+        let heights: Vec<usize> = (0..COMBINATION_LENGTH)
+            .map(|_| rng.below(height as u64 - 1) as usize)
+            .collect();
+        let is_lock = rng.chance(0.5);
+        let mut schematic = String::new();
+        for row in 0..height {
+            for column_height in heights.iter() {
+                // Locks have their pins hanging from the top row, keys grow from the bottom one
+                let filled = match is_lock {
+                    true => row <= *column_height,
+                    false => row >= height - 1 - column_height,
+                };
+                schematic.push(if filled { '#' } else { '.' });
+            }
+            schematic.push('\n');
+        }
+        schematics.push(schematic);
+    }
+    schematics.join("\n")
+}
@@ -0,0 +1,215 @@
+use common::{lines, Answer, Lines, ParseError, Solution};
+
+pub mod generate;
+
+const DAY: u32 = 25;
+
+/// Example input of each part, relative to the directory of the day.
+pub const EXAMPLES: [&str; 1] = ["data/test_input"];
+const TUMBLER_HEIGHT: u32 = 7;
+const COMBINATION_LENGTH: usize = 5;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use common::{check_examples, check_recorded_answers};
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn test_part_one() {
+        let tumblers = parse(EXAMPLE).unwrap();
+        let result = solve_part_one(&tumblers);
+        assert_eq!(result, 3);
+    }
+
+    #[test]
+    fn test_examples() {
+        check_examples::<Day25>(env!("CARGO_MANIFEST_DIR"), &EXAMPLES, &["3"]);
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        check_recorded_answers::<Day25>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
+
+#[derive(Debug)]
+pub enum Tumbler {
+    Lock {
+        combination: [u32; COMBINATION_LENGTH],
+    },
+    Key {
+        combination: [u32; COMBINATION_LENGTH],
+    },
+}
+
+impl Tumbler {
+    fn new_from(block: &Vec<&str>) -> Self {
+        let is_lock = block[0].contains("#");
+        let target_char = match is_lock {
+            true => '#',
+            false => '.',
+        };
+        let mut combination: [u32; COMBINATION_LENGTH] = [0; COMBINATION_LENGTH];
+        for (row, line) in block.iter().enumerate() {
+            for (col, char) in line.chars().enumerate() {
+                if char == target_char {
+                    if is_lock {
+                        combination[col] = row as u32;
+                    } else {
+                        combination[col] = TUMBLER_HEIGHT - row as u32 - 2;
+                    }
+                }
+            }
+        }
+        match is_lock {
+            true => Tumbler::Lock { combination },
+            false => Tumbler::Key { combination },
+        }
+    }
+
+    fn is_lock_compatible(&self, key: &Tumbler) -> bool {
+        if let Tumbler::Key { combination: _ } = self {
+            panic!()
+        };
+        if let Tumbler::Lock { combination: _ } = key {
+            panic!()
+        };
+        let result = match (self, key) {
+            (Tumbler::Key { combination: _ }, _) => panic!(),
+            (_, Tumbler::Lock { combination: _ }) => panic!(),
+            (
+                Tumbler::Lock {
+                    combination: lock_combination,
+                },
+                Tumbler::Key {
+                    combination: key_combination,
+                },
+            ) => {
+                let sum = add(lock_combination, key_combination);
+                sum.iter().all(|&x| x <= TUMBLER_HEIGHT - 2)
+            }
+        };
+        result
+    }
+}
+
+fn is_lock(tumbler: &Tumbler) -> bool {
+    match tumbler {
+        Tumbler::Lock { combination: _ } => true,
+        Tumbler::Key { combination: _ } => false,
+    }
+}
+
+/// Add two arrays element-wise
+fn add(a: &[u32; COMBINATION_LENGTH], b: &[u32; COMBINATION_LENGTH]) -> [u32; COMBINATION_LENGTH] {
+    let mut result = [0; COMBINATION_LENGTH];
+    for i in 0..COMBINATION_LENGTH {
+        result[i] = a[i] + b[i]
+    }
+    result
+}
+
+fn parse_block<'a>(lines: &mut Lines<'a>) -> Result<Option<Vec<&'a str>>, ParseError> {
+    let mut block = vec![];
+    let mut first_line = 0;
+    for line in lines.by_ref() {
+        if line.text.is_empty() {
+            break;
+        };
+        if block.is_empty() {
+            first_line = line.number;
+        }
+        if line.text.len() != COMBINATION_LENGTH {
+            return Err(line.error(line.text, "invalid row length"));
+        }
+        if let Some(i) = line.text.chars().position(|c| c != '#' && c != '.') {
+            return Err(line.error_at(i, "invalid character"));
+        }
+        block.push(line.text);
+    }
+    if block.is_empty() {
+        return Ok(None);
+    }
+    if block.len() != TUMBLER_HEIGHT as usize {
+        let message = "invalid tumbler height";
+        return Err(ParseError::new(DAY, first_line, 1, "", message));
+    }
+    Ok(Some(block))
+}
+
+pub fn parse(content: &str) -> Result<Vec<Tumbler>, ParseError> {
+    let mut lines = lines(DAY, content);
+    let mut tumblers = vec![];
+    while let Some(block) = parse_block(&mut lines)? {
+        tumblers.push(Tumbler::new_from(&block));
+    }
+    Ok(tumblers)
+}
+
+pub fn solve_part_one(tumblers: &[Tumbler]) -> u32 {
+    let mut n_fits = 0;
+    for lock in tumblers.iter().filter(|t| is_lock(t)) {
+        for key in tumblers.iter().filter(|t| !is_lock(t)) {
+            if lock.is_lock_compatible(key) {
+                n_fits += 1;
+            }
+        }
+    }
+    n_fits
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Tumbler>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        solve_part_one(input).into()
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_left(), Direction::NorthWest);
+        assert_eq!(Direction::SouthWest.turn_around(), Direction::NorthEast);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_around().turn_around(), direction);
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for c in ['^', 'v', '>', '<'] {
+            assert_eq!(Direction::from_char(c).unwrap().to_string(), c.to_string());
+        }
+        assert_eq!(Direction::from_char('N'), Some(Direction::North));
+        assert_eq!(Direction::from_char('W'), Some(Direction::West));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_deltas() {
+        assert_eq!(Direction::North.delta(), (0, -1));
+        assert_eq!(Direction::SouthEast.delta(), (1, 1));
+        let total = Direction::ALL
+            .iter()
+            .map(|d| d.delta())
+            .fold((0, 0), |acc, d| (acc.0 + d.0, acc.1 + d.1));
+        assert_eq!(total, (0, 0));
+    }
+}
+
+/// Cardinal or ordinal direction on a grid, where north points to the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Horizontal and vertical directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Diagonal directions, clockwise from north-east.
+    pub const ORDINAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+
+    /// All the directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// Parse a direction from an arrow (`^v<>`) or the initial of a cardinal point (`NSEW`).
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Direction::North),
+            'v' | 'S' => Some(Direction::South),
+            '>' | 'E' => Some(Direction::East),
+            '<' | 'W' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// Return the displacement (dx, dy) of a single step in this direction.
+    pub fn delta(&self) -> (i64, i64) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    pub fn is_cardinal(&self) -> bool {
+        Self::CARDINAL.contains(self)
+    }
+
+    /// Rotate the direction clockwise by the given number of eighths of a turn.
+    fn rotate(&self, eighths: usize) -> Self {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap();
+        Self::ALL[(index + eighths) % 8]
+    }
+
+    /// Turn 90 degrees clockwise.
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Turn 90 degrees counterclockwise.
+    pub fn turn_left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn turn_around(&self) -> Self {
+        self.rotate(4)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let string = match self {
+            Direction::North => "^",
+            Direction::NorthEast => "NE",
+            Direction::East => ">",
+            Direction::SouthEast => "SE",
+            Direction::South => "v",
+            Direction::SouthWest => "SW",
+            Direction::West => "<",
+            Direction::NorthWest => "NW",
+        };
+        write!(f, "{}", string)
+    }
+}
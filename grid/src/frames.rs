@@ -0,0 +1,244 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Grid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PALETTE: &[(char, Rgb)] = &[('#', [255, 0, 0]), ('.', [0, 0, 255])];
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("grid-frames-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_encode_ppm() {
+        let grid = Grid::from_rows(vec![vec!['#', '.'], vec!['?', '#']]);
+        let image = encode(&grid, &Palette::new(PALETTE), Format::Ppm, 1);
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([255, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0]);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn test_encode_scaled_pgm() {
+        let grid = Grid::from_rows(vec![vec!['#', '.']]);
+        let image = encode(&grid, &Palette::new(PALETTE), Format::Pgm, 2);
+        let mut expected = b"P5\n4 2\n255\n".to_vec();
+        expected.extend([76, 76, 29, 29, 76, 76, 29, 29]);
+        assert_eq!(image, expected);
+    }
+
+    #[test]
+    fn test_palette_overrides() {
+        let mut palette = Palette::new(PALETTE);
+        palette.set_overrides("#=00ff00,@=FFFFFF").unwrap();
+        assert_eq!(palette.color('#'), [0, 255, 0]);
+        assert_eq!(palette.color('@'), [255, 255, 255]);
+        assert_eq!(palette.color('.'), [0, 0, 255]);
+        assert!(palette.set_overrides("#=00ff0").is_err());
+        assert!(palette.set_overrides("#00ff00").is_err());
+        assert!(palette.set_overrides("#=gg0000").is_err());
+    }
+
+    #[test]
+    fn test_record_every_nth_step() {
+        let dir = temp_dir("every");
+        let mut frames = Frames::new(&dir, Palette::new(PALETTE)).unwrap();
+        frames.set_every(3);
+        let grid = Grid::new(2, 2, '.');
+        for _ in 0..7 {
+            frames.record(&grid).unwrap();
+        }
+        frames.finish(&grid).unwrap();
+        // Steps 0, 3 and 6 are recorded, so the last one doesn't have to be written again
+        assert_eq!(frames.written(), 3);
+        let names: Vec<_> = fs::read_dir(&dir).unwrap().map(|e| e.unwrap()).collect();
+        assert_eq!(names.len(), 3);
+        assert!(dir.join("frame-00002.ppm").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+/// Color of a pixel, as red, green and blue components.
+pub type Rgb = [u8; 3];
+
+/// Color used for cells missing from the palette.
+const MISSING: Rgb = [0, 0, 0];
+
+/// Binary image formats of the Netpbm family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Color image (`P6`)
+    Ppm,
+    /// Grayscale image (`P5`)
+    Pgm,
+}
+
+impl Format {
+    fn extension(&self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Pgm => "pgm",
+        }
+    }
+}
+
+/// Colors of the cells of a map.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: Vec<(char, Rgb)>,
+}
+
+impl Palette {
+    pub fn new(colors: &[(char, Rgb)]) -> Self {
+        Self {
+            colors: colors.to_vec(),
+        }
+    }
+
+    /// Return the color of a cell, or black if it's not in the palette.
+    pub fn color(&self, cell: char) -> Rgb {
+        match self.colors.iter().find(|(c, _)| *c == cell) {
+            Some((_, color)) => *color,
+            None => MISSING,
+        }
+    }
+
+    /// Replace or add colors given as a comma-separated list like `#=808080,O=ffaa00`.
+    pub fn set_overrides(&mut self, spec: &str) -> Result<(), String> {
+        for entry in spec.split(",") {
+            let mut chars = entry.chars();
+            let (Some(cell), Some('=')) = (chars.next(), chars.next()) else {
+                return Err(format!(
+                    "Invalid palette entry '{entry}', expected 'c=rrggbb'"
+                ));
+            };
+            let hex = chars.as_str();
+            let channel = |i: usize| {
+                hex.get(i..i + 2)
+                    .and_then(|h| u8::from_str_radix(h, 16).ok())
+            };
+            let color = match (hex.len(), channel(0), channel(2), channel(4)) {
+                (6, Some(r), Some(g), Some(b)) => [r, g, b],
+                _ => return Err(format!("Invalid color '{hex}', expected six hex digits")),
+            };
+            self.colors.retain(|(c, _)| *c != cell);
+            self.colors.push((cell, color));
+        }
+        Ok(())
+    }
+}
+
+/// Convert a color to gray using the luma of ITU-R BT.601.
+fn to_gray([r, g, b]: Rgb) -> u8 {
+    let luma = (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000;
+    luma as u8
+}
+
+/// Encode a map as an image, where each cell takes `scale` by `scale` pixels.
+pub fn encode(grid: &Grid<char>, palette: &Palette, format: Format, scale: usize) -> Vec<u8> {
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    let magic = match format {
+        Format::Ppm => "P6",
+        Format::Pgm => "P5",
+    };
+    let mut image = format!("{magic}\n{width} {height}\n255\n").into_bytes();
+    for row in grid.rows() {
+        let mut pixels = vec![];
+        for cell in row {
+            let color = palette.color(*cell);
+            for _ in 0..scale {
+                match format {
+                    Format::Ppm => pixels.extend(color),
+                    Format::Pgm => pixels.push(to_gray(color)),
+                }
+            }
+        }
+        for _ in 0..scale {
+            image.extend(&pixels);
+        }
+    }
+    image
+}
+
+/// Writer of the frames of an animation, as a numbered sequence of images in a directory.
+///
+/// Every step of the animation goes through `record`, but only one in every `every` of them
+/// (including the first one) gets written. Calling `finish` with the last recorded state makes
+/// sure the animation ends with it.
+pub struct Frames {
+    dir: PathBuf,
+    palette: Palette,
+    format: Format,
+    scale: usize,
+    every: usize,
+    step: usize,
+    written: usize,
+}
+
+impl Frames {
+    /// Create the writer, along with its directory if it doesn't exist yet.
+    pub fn new(dir: impl AsRef<Path>, palette: Palette) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: dir.as_ref().to_path_buf(),
+            palette,
+            format: Format::Ppm,
+            scale: 1,
+            every: 1,
+            step: 0,
+            written: 0,
+        })
+    }
+
+    pub fn set_format(&mut self, format: Format) {
+        self.format = format;
+    }
+
+    /// Set the size in pixels of the side of each cell.
+    pub fn set_scale(&mut self, scale: usize) {
+        self.scale = scale.max(1);
+    }
+
+    /// Only write one frame every `every` steps.
+    pub fn set_every(&mut self, every: usize) {
+        self.every = every.max(1);
+    }
+
+    /// Return the number of frames written so far.
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    fn write(&mut self, grid: &Grid<char>) -> io::Result<()> {
+        let fname = format!("frame-{:05}.{}", self.written, self.format.extension());
+        let image = encode(grid, &self.palette, self.format, self.scale);
+        fs::write(self.dir.join(fname), image)?;
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Record a step of the animation.
+    pub fn record(&mut self, grid: &Grid<char>) -> io::Result<()> {
+        if self.step.is_multiple_of(self.every) {
+            self.write(grid)?;
+        }
+        self.step += 1;
+        Ok(())
+    }
+
+    /// Write the last recorded state, unless it's already written.
+    pub fn finish(&mut self, grid: &Grid<char>) -> io::Result<()> {
+        let last_written = self.step > 0 && (self.step - 1).is_multiple_of(self.every);
+        if !last_written {
+            self.write(grid)?;
+        }
+        Ok(())
+    }
+}
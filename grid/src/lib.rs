@@ -0,0 +1,271 @@
+use common::{lines, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+mod direction;
+pub mod frames;
+pub mod search;
+
+pub use direction::Direction;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+#.S.
+..#E
+#...
+";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(1, MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.get(2, 0), Some(&'S'));
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.find(&'E'), Some((3, 1)));
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse(1, "#..\n#.\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Grid::parse_with(1, "#..\n#x.\n", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 2, 0);
+        let mut neighbors: Vec<Point> = grid.neighbors4((0, 0)).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        *grid.get_mut(1, 0).unwrap() = 3;
+        grid[(0, 1)] = 4;
+        assert_eq!(grid.get_mut(2, 0), None);
+        assert_eq!(grid.to_string(), "03\n40\n");
+    }
+}
+
+/// Position in a grid as (x, y), where x is the column and y is the row.
+pub type Point = (usize, usize);
+
+/// Two-dimensional grid stored as a dense vector in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a new grid filled with copies of a single value.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Create a new grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if rows.iter().any(|row| row.len() != width) {
+            panic!("All the rows of a grid must have the same length");
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+        self.cells.get(y * self.width + x)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if !self.contains(x, y) {
+            return None;
+        }
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    /// Move a point by the given displacement, returning `None` if it falls outside the grid.
+    pub fn step(&self, point: Point, dx: i64, dy: i64) -> Option<Point> {
+        let x = point.0 as i64 + dx;
+        let y = point.1 as i64 + dy;
+        if x < 0 || y < 0 || !self.contains(x as usize, y as usize) {
+            return None;
+        }
+        Some((x as usize, y as usize))
+    }
+
+    /// Move a point one step in the given direction, returning `None` if it leaves the grid.
+    pub fn next(&self, point: Point, direction: Direction) -> Option<Point> {
+        let (dx, dy) = direction.delta();
+        self.step(point, dx, dy)
+    }
+
+    /// Iterate over the (up to four) horizontal and vertical neighbors of a point.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.next(point, direction))
+    }
+
+    /// Iterate over the (up to eight) neighbors of a point, including diagonals.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.next(point, direction))
+    }
+
+    /// Iterate over all the points of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i % width, i / width))
+    }
+
+    /// Iterate over all the points of the grid along with their values.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Iterate over the rows of the grid.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Return the first point (in row-major order) that holds the given value.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        let index = self.cells.iter().position(|v| v == value)?;
+        Some((index % self.width, index / self.width))
+    }
+
+    /// Create a new grid by applying a function to every value.
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Parse a grid from a map of characters, converting each one of them with a function.
+    ///
+    /// The function should return `None` for characters that aren't valid in the map.
+    pub fn parse_with<F>(day: u32, input: &str, f: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in lines(day, input) {
+            let mut row_width = 0;
+            for (i, c) in line.text.chars().enumerate() {
+                match f(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(line.error_at(i, "invalid character")),
+                }
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(line.error(line.text, "rows have different lengths"))
+                }
+                Some(_) => (),
+            }
+            height += 1;
+        }
+        if height == 0 {
+            return Err(ParseError::new(day, 1, 1, "", "empty map"));
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+}
+
+impl Grid<char> {
+    /// Parse a grid from a map of characters.
+    pub fn parse(day: u32, input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(day, input, Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.get(point.0, point.1) {
+            Some(value) => value,
+            None => panic!("Point {:?} is outside the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match self.get_mut(point.0, point.1) {
+            Some(value) => value,
+            None => panic!("Point {:?} is outside the grid", point),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
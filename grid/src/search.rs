@@ -0,0 +1,258 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+.....
+.###.
+...#.
+.#...
+";
+
+    fn open_neighbors(grid: &Grid<char>, point: &Point) -> Vec<Point> {
+        grid.neighbors4(*point)
+            .filter(|n| grid[*n] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse(1, MAZE).unwrap();
+        let search = bfs((0, 0), |p| open_neighbors(&grid, p), |p| *p == (4, 3));
+        assert_eq!(search.cost(), Some(7));
+        assert_eq!(search.distance(&(2, 2)), Some(4));
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], (0, 0));
+        assert_eq!(path[7], (4, 3));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let grid = Grid::parse(1, MAZE).unwrap();
+        // Moving right is free, any other move costs 10
+        let neighbors = |p: &Point| -> Vec<(Point, u64)> {
+            open_neighbors(&grid, p)
+                .into_iter()
+                .map(|n| (n, if n.0 > p.0 { 0 } else { 10 }))
+                .collect()
+        };
+        let search = dijkstra((0, 0), neighbors, |p| *p == (4, 3));
+        assert_eq!(search.cost(), Some(30));
+        let heuristic = |p: &Point| 10 * (3 - p.1 as u64);
+        let search = astar((0, 0), neighbors, heuristic, |p| *p == (4, 3));
+        assert_eq!(search.cost(), Some(30));
+        assert_eq!(search.path().unwrap().last(), Some(&(4, 3)));
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let search = bfs(
+            0,
+            |n: &u32| if *n < 5 { vec![n + 1] } else { vec![] },
+            |n| *n == 9,
+        );
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.path(), None);
+        assert_eq!(search.path_to(&5), Some(vec![0, 1, 2, 3, 4, 5]));
+    }
+}
+
+/// Outcome of a search: the distance to every explored state and how it was reached.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    start: S,
+    goal: Option<S>,
+    distances: HashMap<S, u64>,
+    predecessors: HashMap<S, S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new(start: S) -> Self {
+        let mut distances = HashMap::new();
+        distances.insert(start.clone(), 0);
+        Self {
+            start,
+            goal: None,
+            distances,
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Return the goal state that stopped the search, if it was reached.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Return the cost of reaching the goal state.
+    pub fn cost(&self) -> Option<u64> {
+        let goal = self.goal.as_ref()?;
+        self.distance(goal)
+    }
+
+    /// Return the cost of reaching a state, if it was explored.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /// Return the distances to every explored state.
+    pub fn distances(&self) -> &HashMap<S, u64> {
+        &self.distances
+    }
+
+    /// Return the state from which every explored state was reached.
+    pub fn predecessors(&self) -> &HashMap<S, S> {
+        &self.predecessors
+    }
+
+    /// Return the path from the start to the goal state, both of them included.
+    pub fn path(&self) -> Option<Vec<S>> {
+        let goal = self.goal.as_ref()?;
+        self.path_to(goal)
+    }
+
+    /// Return the path from the start to a given state, both of them included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        let mut current = state;
+        while *current != self.start {
+            current = &self.predecessors[current];
+            path.push(current.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Element of the priority queue, ordered by its priority only
+struct Entry<S> {
+    priority: u64,
+    cost: u64,
+    state: S,
+}
+
+impl<S> Ord for Entry<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> PartialEq for Entry<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for Entry<S> {}
+
+/// Breadth-first search, where every move costs one.
+///
+/// The search stops as soon as a state that satisfies `is_goal` is found. If none does, every
+/// state reachable from the start is explored.
+pub fn bfs<S, N, I, G>(start: S, mut neighbors: N, mut is_goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let distance = search.distances[&state] + 1;
+        for neighbor in neighbors(&state) {
+            if search.distances.contains_key(&neighbor) {
+                continue;
+            }
+            search.distances.insert(neighbor.clone(), distance);
+            search.predecessors.insert(neighbor.clone(), state.clone());
+            queue.push_back(neighbor);
+        }
+    }
+    search
+}
+
+/// Dijkstra search, where `neighbors` returns each neighbor along with the cost of moving to it.
+///
+/// The search stops as soon as a state that satisfies `is_goal` is found. If none does, every
+/// state reachable from the start is explored.
+pub fn dijkstra<S, N, I, G>(start: S, neighbors: N, is_goal: G) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(&S) -> bool,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+/// A* search, guided by a heuristic that must never overestimate the cost to reach the goal.
+///
+/// Works like [`dijkstra`], which is the same search with a heuristic that is always zero.
+pub fn astar<S, N, I, H, G>(
+    start: S,
+    mut neighbors: N,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    H: FnMut(&S) -> u64,
+    G: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start.clone());
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse(Entry {
+        priority: heuristic(&start),
+        cost: 0,
+        state: start,
+    }));
+    while let Some(Reverse(entry)) = heap.pop() {
+        // Skip outdated entries, the state was already reached with a lower cost
+        if entry.cost > search.distances[&entry.state] {
+            continue;
+        }
+        if is_goal(&entry.state) {
+            search.goal = Some(entry.state);
+            break;
+        }
+        for (neighbor, step_cost) in neighbors(&entry.state) {
+            let cost = entry.cost + step_cost;
+            if let Some(distance) = search.distances.get(&neighbor) {
+                if cost >= *distance {
+                    continue;
+                }
+            }
+            search.distances.insert(neighbor.clone(), cost);
+            search
+                .predecessors
+                .insert(neighbor.clone(), entry.state.clone());
+            heap.push(Reverse(Entry {
+                priority: cost + heuristic(&neighbor),
+                cost,
+                state: neighbor,
+            }));
+        }
+    }
+    search
+}